@@ -0,0 +1,41 @@
+use crate::card::Card;
+use serde::{Deserialize, Serialize};
+
+/// A single decision a player can make, applied through `Game::apply`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GameAction {
+    /// Play the cards at these hand indices (Step 1)
+    Play(Vec<usize>),
+    /// Skip straight to the enemy attack (Step 4)
+    Yield,
+    /// Discard the cards at these hand indices to absorb the enemy attack
+    Discard(Vec<usize>),
+    /// Solo mode: discard the hand and draw a fresh one (Step 1 or Step 4)
+    UseSoloJester,
+    /// Pick the player who takes the next turn after a Jester
+    ChooseNextPlayer(usize),
+}
+
+impl GameAction {
+    /// Describe the action using the hand it is taken from
+    pub fn describe(&self, hand: &[Card]) -> String {
+        let cards = |indices: &[usize]| -> String {
+            let cards: Vec<String> = indices
+                .iter()
+                .filter_map(|&i| hand.get(i))
+                .map(Card::display)
+                .collect();
+            cards.join(" ")
+        };
+
+        match self {
+            GameAction::Play(indices) => format!("Play {}", cards(indices)),
+            GameAction::Yield => "Yield".to_string(),
+            GameAction::Discard(indices) => format!("Discard {}", cards(indices)),
+            GameAction::UseSoloJester => "Use solo Jester power".to_string(),
+            GameAction::ChooseNextPlayer(player) => {
+                format!("Choose Player {} to go next", player + 1)
+            }
+        }
+    }
+}
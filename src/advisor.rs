@@ -0,0 +1,437 @@
+use crate::action::GameAction;
+use crate::card::{Card, Suit};
+use crate::enemy::Enemy;
+use crate::game::{legal_plays_in, Phase};
+use crate::player::best_discard_in;
+use crate::view::GameView;
+
+/// Score for a line of play that loses the game
+const DEFEAT: i32 = -1000;
+
+/// A suggested action for the viewing seat, and why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hint {
+    pub action: GameAction,
+    pub reason: String,
+}
+
+impl Hint {
+    /// Hand cards the hint plays or discards
+    pub fn cards(&self) -> &[usize] {
+        match &self.action {
+            GameAction::Play(cards) | GameAction::Discard(cards) => cards,
+            _ => &[],
+        }
+    }
+}
+
+/// A candidate action and how good it looks after searching ahead
+struct Candidate {
+    action: GameAction,
+    score: i32,
+    reason: String,
+}
+
+/// Ask the advisor for the viewing seat's best action, using only what that
+/// seat can see. `None` when it isn't their turn.
+pub fn hint(view: &GameView) -> Option<Hint> {
+    if !view.is_my_turn() || view.is_over() {
+        return None;
+    }
+    match view.phase {
+        Phase::Play => play_hint(view),
+        Phase::Discard { required } => discard_hint(view, required),
+        Phase::ChooseNextPlayer => next_player_hint(view),
+    }
+}
+
+/// Search every legal play, and yielding, through the enemy's answer and
+/// the discard that survives it, then a look at the following turn
+fn play_hint(view: &GameView) -> Option<Hint> {
+    let enemy = view.current_enemy.as_ref()?;
+    let mut candidates: Vec<Candidate> = legal_plays_in(view.hand())
+        .into_iter()
+        .map(|play| evaluate_play(view, enemy, play))
+        .collect();
+    // A solo yield behind a full shield changes nothing, so never suggest it
+    let idle_yield = view.is_solo() && enemy.attack <= view.shield_value;
+    if !view.must_play && !idle_yield {
+        candidates.push(evaluate_yield(view, enemy));
+    }
+    if view.can_use_solo_jester() {
+        candidates.push(Candidate {
+            action: GameAction::UseSoloJester,
+            score: DEFEAT / 2,
+            reason: "No play survives the next attack; use a Jester for a fresh hand".to_string(),
+        });
+    }
+
+    // The first of equally good candidates wins, so hints are stable
+    let best = candidates
+        .into_iter()
+        .reduce(|best, next| if next.score > best.score { next } else { best })?;
+    Some(Hint {
+        action: best.action,
+        reason: best.reason,
+    })
+}
+
+/// Work out what playing `play` does this turn and what it leaves for the next
+fn evaluate_play(view: &GameView, enemy: &Enemy, play: Vec<usize>) -> Candidate {
+    let hand = view.hand();
+    let cards: Vec<Card> = play.iter().map(|&i| hand[i]).collect();
+    let rest: Vec<Card> = (0..hand.len())
+        .filter(|i| !play.contains(i))
+        .map(|i| hand[i])
+        .collect();
+    let shown = cards
+        .iter()
+        .map(Card::display)
+        .collect::<Vec<_>>()
+        .join(" + ");
+
+    if cards[0].is_jester() {
+        return evaluate_jester(enemy, play, &rest, shown);
+    }
+
+    let attack: u32 = cards.iter().map(|c| c.value() as u32).sum();
+    let active = |suit: Suit| cards.iter().any(|c| c.suit == suit) && !enemy.is_immune_to(suit);
+    let (damage, shield) = strike(view.shield_value, enemy, &cards);
+    let drawn = diamonds_draw(view, enemy, &cards);
+    let healed = if active(Suit::Hearts) {
+        attack.min(view.discard_count as u32)
+    } else {
+        0
+    };
+
+    // Spending cards costs their value; drawing and healing win some back
+    let mut score = -(attack as i32) + 3 * drawn as i32 + healed as i32;
+    let hp = enemy.current_hp as u32;
+    let target = enemy.card.display();
+
+    if damage >= hp {
+        // The same player goes again against the next enemy, with no attack to survive
+        score += 100 - 2 * (damage - hp) as i32;
+        let reason = if damage == hp {
+            score += 30;
+            format!("exact kill captures {}", target)
+        } else if active(Suit::Clubs) {
+            format!(
+                "Clubs double the damage to {}, defeating {}",
+                damage, target
+            )
+        } else {
+            format!("{} damage defeats {}", damage, target)
+        };
+        return Candidate {
+            action: GameAction::Play(play),
+            score,
+            reason: format!("{}: {}", shown, capitalize(&reason)),
+        };
+    }
+
+    score += 2 * damage as i32;
+    let reason = if active(Suit::Spades) && enemy.attack as u32 <= shield {
+        "Spades shield reduces the next attack to 0".to_string()
+    } else if active(Suit::Clubs) {
+        format!("Clubs double the damage to {}", damage)
+    } else if drawn > 0 {
+        format!("Diamonds draw {} cards", drawn)
+    } else if healed > 0 {
+        format!("Hearts heal {} cards into the tavern", healed)
+    } else if active(Suit::Spades) {
+        format!(
+            "Spades shield reduces the attack to {}",
+            (enemy.attack as u32).saturating_sub(shield)
+        )
+    } else {
+        format!("{} damage to {}", damage, target)
+    };
+
+    let (survival, survival_reason) = survive(view, enemy, &rest, shield, hp - damage);
+    Candidate {
+        action: GameAction::Play(play),
+        score: score + survival,
+        reason: format!("{}: {}{}", shown, reason, survival_reason),
+    }
+}
+
+/// Damage `cards` deal to `enemy`, and the shield standing after they are
+/// played on top of `shield_value`
+pub(crate) fn strike(shield_value: u8, enemy: &Enemy, cards: &[Card]) -> (u32, u32) {
+    let attack: u32 = cards.iter().map(|c| c.value() as u32).sum();
+    let active = |suit: Suit| cards.iter().any(|c| c.suit == suit) && !enemy.is_immune_to(suit);
+    let damage = if active(Suit::Clubs) {
+        attack * 2
+    } else {
+        attack
+    };
+    let shield = shield_value as u32 + if active(Suit::Spades) { attack } else { 0 };
+    (damage, shield)
+}
+
+/// Cards that Diamonds among `cards` would draw for the table: one per point
+/// of attack, dealt around every hand up to its limit
+pub(crate) fn diamonds_draw(view: &GameView, enemy: &Enemy, cards: &[Card]) -> u32 {
+    if !cards.iter().any(|c| c.suit == Suit::Diamonds) || enemy.is_immune_to(Suit::Diamonds) {
+        return 0;
+    }
+    let attack: u32 = cards.iter().map(|c| c.value() as u32).sum();
+    // The played cards leave the current hand, making room for as many
+    let free_space: usize = view
+        .players
+        .iter()
+        .map(|p| p.max_hand_size.saturating_sub(p.hand_size))
+        .sum::<usize>()
+        + cards.len();
+    attack
+        .min(view.tavern_remaining as u32)
+        .min(free_space as u32)
+}
+
+/// A Jester skips the enemy attack and cancels its immunity, which pays off
+/// with cards of the enemy's suit in hand
+fn evaluate_jester(enemy: &Enemy, play: Vec<usize>, rest: &[Card], shown: String) -> Candidate {
+    let suit = enemy.card.suit;
+    let unlocked = if enemy.immunity_cancelled {
+        0
+    } else {
+        rest.iter().filter(|c| c.suit == suit).count()
+    };
+    let reason = if unlocked > 0 {
+        format!(
+            "cancels {}'s immunity, unlocking {} {} in your hand",
+            enemy.card.display(),
+            unlocked,
+            suit.name()
+        )
+    } else {
+        "skips the enemy attack, and you pick who goes next".to_string()
+    };
+    Candidate {
+        action: GameAction::Play(play),
+        score: 10 + 4 * unlocked as i32,
+        reason: format!("{}: {}", shown, capitalize(&reason)),
+    }
+}
+
+/// Yielding keeps the hand for later but still has to survive the attack
+fn evaluate_yield(view: &GameView, enemy: &Enemy) -> Candidate {
+    let hp = enemy.current_hp as u32;
+    let (survival, survival_reason) =
+        survive(view, enemy, view.hand(), view.shield_value as u32, hp);
+    let reason = if enemy.attack <= view.shield_value {
+        "Yield: your shield already absorbs the attack".to_string()
+    } else {
+        format!("Yield and keep your attack for later{}", survival_reason)
+    };
+    Candidate {
+        action: GameAction::Yield,
+        score: survival - 5,
+        reason,
+    }
+}
+
+/// Score surviving the enemy attack with `hand` behind `shield`, then
+/// whether what is left could finish an enemy with `hp_left` next turn
+fn survive(
+    view: &GameView,
+    enemy: &Enemy,
+    hand: &[Card],
+    shield: u32,
+    hp_left: u32,
+) -> (i32, String) {
+    let incoming = (enemy.attack as u32).saturating_sub(shield) as u8;
+    let (mut score, left, mut reason) = if incoming == 0 {
+        (10, hand.to_vec(), String::new())
+    } else {
+        match best_discard_in(hand, incoming, Some(enemy)) {
+            Some(discard) => {
+                let value: i32 = discard.iter().map(|&i| hand[i].value() as i32).sum();
+                let left = (0..hand.len())
+                    .filter(|i| !discard.contains(i))
+                    .map(|i| hand[i])
+                    .collect();
+                (-value, left, format!(", then discard {} to survive", value))
+            }
+            None if view.can_use_solo_jester() => (
+                DEFEAT / 4,
+                Vec::new(),
+                ", but only a Jester could survive the attack".to_string(),
+            ),
+            None => (
+                DEFEAT,
+                Vec::new(),
+                ", but you can't survive the attack".to_string(),
+            ),
+        }
+    };
+
+    // One turn further: can the cards left over finish the enemy off?
+    let finishes = legal_plays_in(&left).into_iter().any(|play| {
+        let attack: u32 = play.iter().map(|&i| left[i].value() as u32).sum();
+        let clubs = play
+            .iter()
+            .any(|&i| left[i].suit == Suit::Clubs && !enemy.is_immune_to(Suit::Clubs));
+        (if clubs { attack * 2 } else { attack }) >= hp_left
+    });
+    if finishes && score > DEFEAT / 2 {
+        score += 20;
+        reason.push_str(", leaving a finishing blow for next turn");
+    }
+    (score, reason)
+}
+
+/// Suggest the least wasteful discard, or a solo Jester if nothing covers the attack
+fn discard_hint(view: &GameView, required: u8) -> Option<Hint> {
+    let hand = view.hand();
+    match view.suggested_discard() {
+        Some(discard) => {
+            let value: u8 = discard.iter().map(|&i| hand[i].value()).sum();
+            let reason = if value == required {
+                format!("Covers the {} damage exactly", required)
+            } else {
+                format!(
+                    "Covers the {} damage with the least to spare ({})",
+                    required,
+                    value - required
+                )
+            };
+            Some(Hint {
+                action: GameAction::Discard(discard),
+                reason,
+            })
+        }
+        None if view.can_use_solo_jester() => Some(Hint {
+            action: GameAction::UseSoloJester,
+            reason: format!(
+                "Your hand can't cover {}; use a Jester for a fresh hand",
+                required
+            ),
+        }),
+        None => None,
+    }
+}
+
+/// After a Jester, hand the turn to whoever holds the most cards
+pub(crate) fn next_player_hint(view: &GameView) -> Option<Hint> {
+    let count = view.players.len();
+    let (seat, player) = (0..count)
+        .map(|offset| (view.current_player + 1 + offset) % count)
+        .map(|seat| (seat, &view.players[seat]))
+        .reduce(|best, next| {
+            if next.1.hand_size > best.1.hand_size {
+                next
+            } else {
+                best
+            }
+        })?;
+    Some(Hint {
+        action: GameAction::ChooseNextPlayer(seat),
+        reason: format!(
+            "{} holds the most cards ({})",
+            player.name, player.hand_size
+        ),
+    })
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Rank;
+    use crate::game::{solo_against, Game};
+
+    #[test]
+    fn test_hint_finds_an_exact_kill() {
+        let mut game = solo_against(
+            Card::new(Suit::Spades, Rank::Queen),
+            vec![
+                Card::new(Suit::Hearts, Rank::Nine),
+                Card::new(Suit::Diamonds, Rank::Four),
+                Card::new(Suit::Clubs, Rank::Three),
+                Card::new(Suit::Hearts, Rank::Seven),
+            ],
+        );
+        game.current_enemy.as_mut().unwrap().current_hp = 7;
+
+        let hint = hint(&GameView::for_seat(&game, 0)).unwrap();
+        assert_eq!(hint.action, GameAction::Play(vec![3]));
+        assert_eq!(hint.reason, "7♥: Exact kill captures Q♠");
+    }
+
+    #[test]
+    fn test_hint_raises_a_shield_that_stops_the_attack() {
+        let mut game = solo_against(
+            Card::new(Suit::Hearts, Rank::King),
+            vec![
+                Card::new(Suit::Spades, Rank::Ten),
+                Card::new(Suit::Hearts, Rank::Three),
+                Card::new(Suit::Hearts, Rank::Six),
+            ],
+        );
+        game.shield_value = 10;
+
+        let hint = hint(&GameView::for_seat(&game, 0)).unwrap();
+        assert_eq!(hint.action, GameAction::Play(vec![0]));
+        assert!(hint
+            .reason
+            .contains("Spades shield reduces the next attack to 0"));
+        assert_eq!(hint.cards(), &[0]);
+    }
+
+    #[test]
+    fn test_hint_avoids_plays_that_cannot_survive() {
+        // Playing the Ten leaves too little to cover the Jack's attack
+        let game = solo_against(
+            Card::new(Suit::Spades, Rank::Jack),
+            vec![
+                Card::new(Suit::Hearts, Rank::Ten),
+                Card::new(Suit::Hearts, Rank::Two),
+                Card::new(Suit::Diamonds, Rank::Two),
+            ],
+        );
+
+        let hint = hint(&GameView::for_seat(&game, 0)).unwrap();
+        let GameAction::Play(cards) = &hint.action else {
+            panic!("expected a play, got {:?}", hint.action);
+        };
+        assert!(!cards.contains(&0), "{}", hint.reason);
+    }
+
+    #[test]
+    fn test_hint_suggests_discards_and_next_players() {
+        let mut game = solo_against(
+            Card::new(Suit::Spades, Rank::Jack),
+            vec![
+                Card::new(Suit::Hearts, Rank::Ten),
+                Card::new(Suit::Hearts, Rank::Six),
+                Card::new(Suit::Diamonds, Rank::Four),
+            ],
+        );
+        game.phase = Phase::Discard { required: 10 };
+        let hint = hint(&GameView::for_seat(&game, 0)).unwrap();
+        assert_eq!(hint.action, GameAction::Discard(vec![0]));
+        assert_eq!(hint.reason, "Covers the 10 damage exactly");
+
+        let mut game = Game::new_with_seed(3, 20);
+        game.phase = Phase::ChooseNextPlayer;
+        game.players[2].hand.push(Card::new(Suit::Clubs, Rank::Two));
+        game.players[0].hand.truncate(2);
+        let view = GameView::for_seat(&game, 0);
+        assert_eq!(
+            super::hint(&view).unwrap().action,
+            GameAction::ChooseNextPlayer(2)
+        );
+
+        // Nothing to suggest on someone else's turn
+        assert!(super::hint(&GameView::for_seat(&game, 1)).is_none());
+    }
+}
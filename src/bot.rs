@@ -0,0 +1,419 @@
+use crate::action::GameAction;
+use crate::advisor::{self, strike};
+use crate::card::Card;
+use crate::enemy::Enemy;
+use crate::game::{Game, GameRng, Phase};
+use crate::mcts::{Budget, MctsBot};
+use crate::player::best_discard_in;
+use crate::view::GameView;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// A player the computer controls. Bots only see what their seat may see
+/// (their own hand and everyone's hand counts), so they can fill a seat
+/// without cheating.
+pub trait Bot {
+    /// Name used to pick the bot, e.g. `--bot greedy`
+    fn name(&self) -> &'static str;
+
+    /// Choose the next action, for whichever phase the turn is in; only
+    /// called on the bot's own turn
+    fn choose(&mut self, view: &GameView) -> GameAction;
+
+    /// Choose with the whole game at hand, as the simulator does. Search bots
+    /// override this to deal out the cards they can't see; they must not
+    /// peek at them.
+    fn choose_from_game(&mut self, game: &Game) -> GameAction {
+        self.choose(&GameView::for_seat(game, game.current_player))
+    }
+}
+
+/// Names accepted by `by_name`
+pub const BOT_NAMES: &[&str] = &["heuristic", "greedy", "random", "advisor", "mcts"];
+
+/// Create the bot called `name`, if there is one. `seed` drives any
+/// random choices it makes, and `budget` limits bots that search.
+pub fn by_name(name: &str, seed: u64, budget: Budget) -> Option<Box<dyn Bot>> {
+    match name {
+        "heuristic" => Some(Box::new(HeuristicBot)),
+        "greedy" => Some(Box::new(GreedyBot)),
+        "random" => Some(Box::new(RandomBot::new(seed))),
+        "advisor" => Some(Box::new(AdvisorBot)),
+        "mcts" => Some(Box::new(MctsBot::new(seed, budget))),
+        _ => None,
+    }
+}
+
+/// Take the turns of the bots sitting at `bots`, given as (seat, bot), until
+/// a person is to move or the game is over. Returns true if any bot acted.
+pub fn take_turns(game: &mut Game, bots: &mut [(usize, &mut dyn Bot)]) -> bool {
+    let mut acted = false;
+    while !game.is_over() {
+        let seat = game.current_player;
+        let Some((_, bot)) = bots.iter_mut().find(|(s, _)| *s == seat) else {
+            break;
+        };
+        let action = bot.choose_from_game(game);
+        if game.apply_as(seat, action).is_err() {
+            // Don't leave the seat stuck on a bad choice: any legal action will do
+            let fallback = game.candidate_actions(true).into_iter().next();
+            let Some(action) = fallback else {
+                break;
+            };
+            if game.apply_as(seat, action).is_err() {
+                break;
+            }
+        }
+        acted = true;
+    }
+    acted
+}
+
+/// Follows the hint the advisor would give a human player
+pub struct AdvisorBot;
+
+impl Bot for AdvisorBot {
+    fn name(&self) -> &'static str {
+        "advisor"
+    }
+
+    fn choose(&mut self, view: &GameView) -> GameAction {
+        advisor::hint(view).map_or(GameAction::Yield, |hint| hint.action)
+    }
+}
+
+/// Picks uniformly among the legal actions, as a baseline for the others
+pub struct RandomBot {
+    rng: GameRng,
+}
+
+impl RandomBot {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: GameRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Bot for RandomBot {
+    fn name(&self) -> &'static str {
+        "random"
+    }
+
+    fn choose(&mut self, view: &GameView) -> GameAction {
+        match view.phase {
+            Phase::Play => {
+                let mut options: Vec<GameAction> = view
+                    .legal_plays()
+                    .into_iter()
+                    .map(GameAction::Play)
+                    .collect();
+                if !view.must_play {
+                    options.push(GameAction::Yield);
+                }
+                if view.can_use_solo_jester() {
+                    options.push(GameAction::UseSoloJester);
+                }
+                options
+                    .choose(&mut self.rng)
+                    .cloned()
+                    .unwrap_or(GameAction::Yield)
+            }
+            Phase::Discard { required } => {
+                // Discard cards in a random order until the attack is covered
+                let hand = view.hand();
+                let mut order: Vec<usize> = (0..hand.len()).collect();
+                order.shuffle(&mut self.rng);
+                let mut cards = Vec::new();
+                let mut value = 0;
+                for i in order {
+                    if value >= required {
+                        break;
+                    }
+                    value += hand[i].value();
+                    cards.push(i);
+                }
+                if value < required {
+                    return GameAction::UseSoloJester;
+                }
+                cards.sort_unstable();
+                GameAction::Discard(cards)
+            }
+            Phase::ChooseNextPlayer => {
+                GameAction::ChooseNextPlayer(self.rng.gen_range(0..view.players.len()))
+            }
+        }
+    }
+}
+
+/// Always plays for the most damage this turn, and only thinks about the
+/// enemy attack once it has to discard
+pub struct GreedyBot;
+
+impl Bot for GreedyBot {
+    fn name(&self) -> &'static str {
+        "greedy"
+    }
+
+    fn choose(&mut self, view: &GameView) -> GameAction {
+        let Some(enemy) = &view.current_enemy else {
+            return GameAction::Yield;
+        };
+        match view.phase {
+            Phase::Play => {
+                let hand = view.hand();
+                let play = view
+                    .legal_plays()
+                    .into_iter()
+                    // The first of equal plays wins, so singles beat combos
+                    .rev()
+                    .max_by_key(|play| strike(view.shield_value, enemy, &cards_at(hand, play)).0);
+                match play {
+                    Some(play) => GameAction::Play(play),
+                    None if view.can_use_solo_jester() => GameAction::UseSoloJester,
+                    None => GameAction::Yield,
+                }
+            }
+            Phase::Discard { required } => discard_or_jester(view, required),
+            Phase::ChooseNextPlayer => choose_next_player(view),
+        }
+    }
+}
+
+/// Goes for exact kills, raises Spades to stop the next attack, only plays
+/// what it can still survive, and uses a solo Jester at the start of a turn
+/// rather than when the attack is already coming
+pub struct HeuristicBot;
+
+impl HeuristicBot {
+    /// Rank a play as (tier, score): higher tiers are always preferred
+    fn rate(view: &GameView, enemy: &Enemy, hand: &[Card], play: &[usize]) -> (u8, i32) {
+        let cards = cards_at(hand, play);
+        let rest: Vec<Card> = (0..hand.len())
+            .filter(|i| !play.contains(i))
+            .map(|i| hand[i])
+            .collect();
+        let spent: i32 = cards.iter().map(|c| c.value() as i32).sum();
+        if cards[0].is_jester() {
+            // No damage, but no attack to survive either
+            return (1, 0);
+        }
+
+        let (damage, shield) = strike(view.shield_value, enemy, &cards);
+        let hp = enemy.current_hp as u32;
+        // Cards drawn by Diamonds make up for the ones spent
+        let drawn = advisor::diamonds_draw(view, enemy, &cards) as i32;
+        if damage == hp {
+            return (5, -spent);
+        }
+        if damage > hp {
+            return (4, -((damage - hp) as i32) - spent);
+        }
+        let incoming = (enemy.attack as u32).saturating_sub(shield) as u8;
+        if incoming == 0 {
+            return (3, damage as i32 - spent + 3 * drawn);
+        }
+        match best_discard_in(&rest, incoming, Some(enemy)) {
+            Some(discard) => {
+                let lost: i32 = discard.iter().map(|&i| rest[i].value() as i32).sum();
+                (2, damage as i32 - lost + 3 * drawn)
+            }
+            None => (0, damage as i32),
+        }
+    }
+}
+
+impl Bot for HeuristicBot {
+    fn name(&self) -> &'static str {
+        "heuristic"
+    }
+
+    fn choose(&mut self, view: &GameView) -> GameAction {
+        let Some(enemy) = &view.current_enemy else {
+            return GameAction::Yield;
+        };
+        match view.phase {
+            Phase::Play => {
+                let hand = view.hand();
+                let best = view
+                    .legal_plays()
+                    .into_iter()
+                    .rev()
+                    .map(|play| (Self::rate(view, enemy, hand, &play), play))
+                    .max_by_key(|(rating, _)| *rating);
+
+                let incoming = enemy.attack.saturating_sub(view.shield_value);
+                let yield_rating = if view.must_play || (view.is_solo() && incoming == 0) {
+                    // Can't yield, or a solo yield would change nothing
+                    None
+                } else if incoming == 0 {
+                    Some((1, 0))
+                } else {
+                    best_discard_in(hand, incoming, Some(enemy)).map(|discard| {
+                        let lost: i32 = discard.iter().map(|&i| hand[i].value() as i32).sum();
+                        (1, -lost)
+                    })
+                };
+
+                match (best, yield_rating) {
+                    (Some((rating, play)), _) if rating.0 >= 2 => GameAction::Play(play),
+                    (Some((rating, play)), Some(yielding)) if rating >= yielding => {
+                        GameAction::Play(play)
+                    }
+                    (_, Some(_)) => GameAction::Yield,
+                    // Nothing survives: a fresh hand is the last resort
+                    _ if view.can_use_solo_jester() => GameAction::UseSoloJester,
+                    (Some((_, play)), None) => GameAction::Play(play),
+                    (None, None) => GameAction::Yield,
+                }
+            }
+            Phase::Discard { required } => discard_or_jester(view, required),
+            Phase::ChooseNextPlayer => choose_next_player(view),
+        }
+    }
+}
+
+/// The cards at `indices` in `hand`
+fn cards_at(hand: &[Card], indices: &[usize]) -> Vec<Card> {
+    indices.iter().map(|&i| hand[i]).collect()
+}
+
+/// The least wasteful discard, or a solo Jester if nothing covers the attack
+fn discard_or_jester(view: &GameView, required: u8) -> GameAction {
+    best_discard_in(view.hand(), required, view.current_enemy.as_ref())
+        .map_or(GameAction::UseSoloJester, GameAction::Discard)
+}
+
+/// After a Jester, pass the turn where the advisor would
+fn choose_next_player(view: &GameView) -> GameAction {
+    advisor::next_player_hint(view)
+        .map_or(GameAction::ChooseNextPlayer(view.current_player), |hint| {
+            hint.action
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Rank, Suit};
+    use crate::game::{solo_against, Game};
+
+    #[test]
+    fn test_bots_are_found_by_name() {
+        for name in BOT_NAMES {
+            assert_eq!(
+                by_name(name, 0, Budget::default()).map(|bot| bot.name()),
+                Some(*name)
+            );
+        }
+        assert!(by_name("nobody", 0, Budget::default()).is_none());
+    }
+
+    #[test]
+    fn test_bots_only_make_legal_moves() {
+        for name in BOT_NAMES {
+            for seed in 0..20 {
+                let mut game = Game::new_with_seed(1 + seed as usize % 4, seed);
+                let budget = Budget {
+                    iterations: 5,
+                    time: None,
+                };
+                let mut bot = by_name(name, seed, budget).unwrap();
+                while !game.is_over() && game.actions.len() < 500 {
+                    let action = bot.choose_from_game(&game);
+                    if let Err(e) = game.apply(action.clone()) {
+                        panic!(
+                            "{} bot, seed {}: {:?} was rejected: {}",
+                            name, seed, action, e
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_greedy_bot_plays_for_the_most_damage() {
+        // Doubled by Clubs, the 6♣ outdoes the 9♥
+        let game = solo_against(
+            Card::new(Suit::Spades, Rank::King),
+            vec![
+                Card::new(Suit::Hearts, Rank::Nine),
+                Card::new(Suit::Clubs, Rank::Six),
+                Card::new(Suit::Diamonds, Rank::Two),
+            ],
+        );
+        let view = GameView::for_seat(&game, 0);
+        assert_eq!(GreedyBot.choose(&view), GameAction::Play(vec![1]));
+    }
+
+    #[test]
+    fn test_heuristic_bot_prefers_exact_kills_and_spade_shields() {
+        let mut game = solo_against(
+            Card::new(Suit::Hearts, Rank::Jack),
+            vec![
+                Card::new(Suit::Clubs, Rank::Nine),
+                Card::new(Suit::Spades, Rank::Five),
+                Card::new(Suit::Diamonds, Rank::Three),
+            ],
+        );
+        game.current_enemy.as_mut().unwrap().current_hp = 5;
+        let view = GameView::for_seat(&game, 0);
+        assert_eq!(HeuristicBot.choose(&view), GameAction::Play(vec![1]));
+
+        // With the Jack at full health, the 5♠ tops up the shield to stop its attack
+        game.current_enemy.as_mut().unwrap().current_hp = 20;
+        game.shield_value = 5;
+        let view = GameView::for_seat(&game, 0);
+        assert_eq!(HeuristicBot.choose(&view), GameAction::Play(vec![1]));
+    }
+
+    #[test]
+    fn test_heuristic_bot_uses_a_jester_before_a_hopeless_turn() {
+        let game = solo_against(
+            Card::new(Suit::Spades, Rank::King),
+            vec![
+                Card::new(Suit::Hearts, Rank::Two),
+                Card::new(Suit::Diamonds, Rank::Three),
+            ],
+        );
+        let view = GameView::for_seat(&game, 0);
+        assert_eq!(HeuristicBot.choose(&view), GameAction::UseSoloJester);
+        assert_eq!(GreedyBot.choose(&view), GameAction::Play(vec![1]));
+    }
+
+    /// Always asks for a card it doesn't have
+    struct StubbornBot;
+
+    impl Bot for StubbornBot {
+        fn name(&self) -> &'static str {
+            "stubborn"
+        }
+
+        fn choose(&mut self, _view: &GameView) -> GameAction {
+            GameAction::Play(vec![99])
+        }
+    }
+
+    #[test]
+    fn test_rejected_bot_actions_fall_back_to_a_legal_one() {
+        let mut game = Game::new_with_seed(2, 8);
+        let mut bot = StubbornBot;
+        assert!(take_turns(&mut game, &mut [(0, &mut bot)]));
+        assert!(game.current_player == 1 || game.is_over());
+    }
+
+    #[test]
+    fn test_bots_pass_the_turn_like_the_advisor() {
+        let mut game = Game::new_with_seed(3, 5);
+        game.phase = Phase::ChooseNextPlayer;
+        game.players[0].hand.truncate(2);
+        game.players[2].hand.truncate(4);
+
+        let view = GameView::for_seat(&game, 0);
+        let hint = advisor::hint(&view).unwrap();
+        assert_eq!(hint.action, GameAction::ChooseNextPlayer(1));
+        assert_eq!(HeuristicBot.choose(&view), hint.action);
+        assert_eq!(GreedyBot.choose(&view), hint.action);
+    }
+}
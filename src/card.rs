@@ -18,6 +18,15 @@ impl Suit {
         }
     }
 
+    pub fn name(&self) -> &'static str {
+        match self {
+            Suit::Hearts => "Hearts",
+            Suit::Diamonds => "Diamonds",
+            Suit::Clubs => "Clubs",
+            Suit::Spades => "Spades",
+        }
+    }
+
     pub fn is_red(&self) -> bool {
         matches!(self, Suit::Hearts | Suit::Diamonds)
     }
@@ -111,4 +120,17 @@ impl Card {
     pub fn display(&self) -> String {
         format!("{}{}", self.rank.display(), self.suit.symbol())
     }
+
+    /// Returns the full name of the card, e.g. "Jack of Spades"
+    pub fn name(&self) -> String {
+        let rank = match self.rank {
+            Rank::Ace => "Ace",
+            Rank::Jack => "Jack",
+            Rank::Queen => "Queen",
+            Rank::King => "King",
+            Rank::Jester => return "Jester".to_string(),
+            _ => return format!("{} of {}", self.rank.display(), self.suit.name()),
+        };
+        format!("{} of {}", rank, self.suit.name())
+    }
 }
@@ -0,0 +1,215 @@
+use crate::error::ChatError;
+use serde::{Deserialize, Serialize};
+
+/// Longest chat message allowed, in characters
+pub const MAX_MESSAGE_LENGTH: usize = 120;
+
+/// The only messages strict chat allows: signals that don't reveal a hand
+pub const SIGNALS: &[&str] = &[
+    "I can't help with that",
+    "I can help with that",
+    "Please yield",
+    "Please don't yield",
+    "Give me a moment",
+    "Good luck!",
+    "Well played!",
+];
+
+/// Card ranks as players might write them, including the numbers on the cards
+const RANK_WORDS: &[&str] = &[
+    "ace", "aces", "two", "twos", "three", "threes", "four", "fours", "five", "fives", "six",
+    "sixes", "seven", "sevens", "eight", "eights", "nine", "nines", "ten", "tens", "jack", "jacks",
+    "queen", "queens", "king", "kings", "jester", "jesters", "1", "2", "3", "4", "5", "6", "7",
+    "8", "9", "10", "j", "q", "k",
+];
+
+/// Suits as players might write them
+const SUIT_WORDS: &[&str] = &[
+    "heart", "hearts", "diamond", "diamonds", "club", "clubs", "spade", "spades",
+];
+
+const SUIT_SYMBOLS: &[char] = &['♥', '♦', '♣', '♠', '♡', '♢', '♧', '♤'];
+
+/// How freely players may talk, chosen before the game starts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatMode {
+    /// Any text that doesn't name card ranks or suits
+    #[default]
+    Free,
+    /// Only the fixed `SIGNALS`
+    Strict,
+}
+
+impl std::fmt::Display for ChatMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ChatMode::Free => write!(f, "free"),
+            ChatMode::Strict => write!(f, "strict"),
+        }
+    }
+}
+
+/// One line of table talk
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub from: String,
+    pub text: String,
+}
+
+/// Check a message against the communication rules at a table of `seats`,
+/// returning the text to send: trimmed, or the signal it names in strict mode
+pub fn check_message(mode: ChatMode, seats: usize, text: &str) -> Result<String, ChatError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(ChatError::Empty);
+    }
+    if text.chars().count() > MAX_MESSAGE_LENGTH {
+        return Err(ChatError::TooLong {
+            max: MAX_MESSAGE_LENGTH,
+        });
+    }
+
+    match mode {
+        ChatMode::Strict => SIGNALS
+            .iter()
+            .find(|signal| signal.eq_ignore_ascii_case(text))
+            .map(|signal| signal.to_string())
+            .ok_or(ChatError::NotASignal),
+        ChatMode::Free if mentions_cards(text, seats) => Err(ChatError::MentionsCards),
+        ChatMode::Free => Ok(text.to_string()),
+    }
+}
+
+/// Check if text names a card rank or suit, e.g. "I have the 7♥" or "two kings",
+/// at a table of `seats`
+pub fn mentions_cards(text: &str, seats: usize) -> bool {
+    if text.contains(SUIT_SYMBOLS) {
+        return true;
+    }
+
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    words.iter().enumerate().any(|(i, word)| {
+        // "Player 2" is a seat name, not a card, but only for seats at this table
+        let seat = i > 0 && words[i - 1] == "player" && (1..=seats).any(|n| n.to_string() == *word);
+        !seat
+            && (RANK_WORDS.contains(&word.as_str())
+                || SUIT_WORDS.contains(&word.as_str())
+                || is_card_code(word))
+    })
+}
+
+/// Short card codes such as "kh", "10s" or "1d". Aces have no letter code,
+/// since "as", "ah", "ad" and "ac" are everyday words.
+fn is_card_code(word: &str) -> bool {
+    let Some(rank) = word.strip_suffix(['h', 'd', 'c', 's']) else {
+        return false;
+    };
+    matches!(rank, "j" | "q" | "k") || matches!(rank.parse::<u8>(), Ok(1..=10))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_free_chat_blocks_ranks_and_suits() {
+        assert_eq!(
+            check_message(ChatMode::Free, 4, "  Nice one team  "),
+            Ok("Nice one team".to_string())
+        );
+
+        for text in [
+            "I have the 7♥",
+            "two kings here",
+            "Got any Hearts?",
+            "I can play 10",
+            "holding kh and 5s",
+            "my JESTER is ready",
+            "Player 3 has a 7",
+            "player 10",
+            "player 9",
+            "player 1 player 7",
+        ] {
+            assert_eq!(
+                check_message(ChatMode::Free, 4, text),
+                Err(ChatError::MentionsCards),
+                "{}",
+                text
+            );
+        }
+
+        // Words that merely contain a rank are fine
+        assert!(!mentions_cards(
+            "Kingslayer is tense, attention everyone",
+            4
+        ));
+        assert!(!mentions_cards("has this gone well?", 4));
+
+        // Nor are words that look like Ace codes, or seats named by number
+        for text in [
+            "as i said",
+            "ah nice",
+            "ad hoc",
+            "AC is on",
+            "Player 2, please yield",
+        ] {
+            assert_eq!(
+                check_message(ChatMode::Free, 4, text),
+                Ok(text.to_string()),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_free_chat_only_names_seats_at_the_table() {
+        assert_eq!(
+            check_message(ChatMode::Free, 2, "Player 2, please yield"),
+            Ok("Player 2, please yield".to_string())
+        );
+        for text in ["player 3", "Player 4, please yield"] {
+            assert_eq!(
+                check_message(ChatMode::Free, 2, text),
+                Err(ChatError::MentionsCards),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_strict_chat_only_allows_signals() {
+        assert_eq!(
+            check_message(ChatMode::Strict, 4, "please yield"),
+            Ok("Please yield".to_string())
+        );
+        assert_eq!(
+            check_message(ChatMode::Strict, 4, "Nice one team"),
+            Err(ChatError::NotASignal)
+        );
+        for signal in SIGNALS {
+            assert!(!mentions_cards(signal, 4), "{}", signal);
+        }
+    }
+
+    #[test]
+    fn test_chat_rejects_empty_and_long_messages() {
+        assert_eq!(
+            check_message(ChatMode::Free, 4, "   "),
+            Err(ChatError::Empty)
+        );
+        let long = "a".repeat(MAX_MESSAGE_LENGTH + 1);
+        assert_eq!(
+            check_message(ChatMode::Free, 4, &long),
+            Err(ChatError::TooLong {
+                max: MAX_MESSAGE_LENGTH
+            })
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use crossterm::event::KeyCode;
+use kingslayer::chat::SIGNALS;
+use kingslayer::ChatMode;
+
+/// A chat message being written: free text, or one of the signals in strict mode
+pub enum ChatBox {
+    Typing(String),
+    Picking(usize), // Highlighted signal
+}
+
+/// What a key press did to the chat box
+pub enum ChatInput {
+    Editing,
+    Send(String),
+    Cancel,
+}
+
+impl ChatBox {
+    /// An empty chat box for the table's chat rules
+    pub fn open(mode: ChatMode) -> Self {
+        match mode {
+            ChatMode::Free => ChatBox::Typing(String::new()),
+            ChatMode::Strict => ChatBox::Picking(0),
+        }
+    }
+
+    /// Handle a key press while the chat box has focus
+    pub fn handle_key(&mut self, code: KeyCode) -> ChatInput {
+        match (self, code) {
+            (_, KeyCode::Esc) => ChatInput::Cancel,
+            (ChatBox::Typing(text), KeyCode::Enter) => ChatInput::Send(text.clone()),
+            (ChatBox::Typing(text), KeyCode::Backspace) => {
+                text.pop();
+                ChatInput::Editing
+            }
+            (ChatBox::Typing(text), KeyCode::Char(c)) => {
+                text.push(c);
+                ChatInput::Editing
+            }
+            (ChatBox::Picking(cursor), KeyCode::Enter) => {
+                ChatInput::Send(SIGNALS[*cursor].to_string())
+            }
+            (ChatBox::Picking(cursor), KeyCode::Up) => {
+                *cursor = (*cursor + SIGNALS.len() - 1) % SIGNALS.len();
+                ChatInput::Editing
+            }
+            (ChatBox::Picking(cursor), KeyCode::Down) => {
+                *cursor = (*cursor + 1) % SIGNALS.len();
+                ChatInput::Editing
+            }
+            (ChatBox::Picking(_), KeyCode::Char(c)) => {
+                match c.to_digit(10).map(|digit| digit as usize) {
+                    Some(digit) if (1..=SIGNALS.len()).contains(&digit) => {
+                        ChatInput::Send(SIGNALS[digit - 1].to_string())
+                    }
+                    _ => ChatInput::Editing,
+                }
+            }
+            _ => ChatInput::Editing,
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use kingslayer::bot::BOT_NAMES;
+use kingslayer::mcts::Budget;
+use kingslayer::net::DEFAULT_PORT;
+use kingslayer::solver::DEFAULT_NODE_LIMIT;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage:
+  kingslayer [--players <1-4>] [--seed <u64>] [--resume] [--free-undo] [--strict-chat]
+             [--bots <n>] [--bot <name>]
+                                 Play solo, or hot-seat with 2-4 players; --bots
+                                 puts a bot in each of the last n seats
+  kingslayer replay <file>       Step through a recorded game
+  kingslayer host [--port <port>] [--name <name>] [--seed <u64>] [--show-hands]
+                  [--strict-chat] [--bots <n>] [--bot <name>]
+                                 Host a LAN game for 2-4 players (default port 7878);
+                                 --show-hands lets spectators see every hand, and
+                                 --bots seats n bots in the lobby
+  kingslayer join <addr> [--name <name>] [--token <token>]
+                                 Join a LAN game, e.g. 192.168.1.20:7878, or
+                                 take back a dropped seat with its session token
+  kingslayer watch <addr> [--name <name>]
+                                 Watch a LAN game without taking a seat
+  kingslayer simulate [--games <n>] [--bot <name>] [--seed <u64>] [--players <1-4>]
+                      [--iterations <n>] [--think-ms <ms>]
+                                 Play games headless with a bot in every seat and
+                                 report the results (default 1000 solo games);
+                                 --iterations and --think-ms limit the mcts bot
+  kingslayer solve --seed <u64> [--nodes <n>] [--save <file>]
+                                 Search a solo deal with every card known for a
+                                 winning line using the fewest Jesters; --save
+                                 writes the line as a replay
+  kingslayer bot-server [--players <1-4>] [--seed <u64>]
+                                 Play one game over stdin/stdout in JSON lines, for
+                                 bots written in other languages
+
+Undoing a move marks the game as assisted (no victory rank) unless --free-undo is given.
+--strict-chat limits multiplayer chat to a fixed set of signals.
+--bot picks the bot for --bots (heuristic by default), as for simulate.";
+
+/// What the binary was asked to do
+pub enum Command {
+    /// Play interactively
+    Play(Options),
+    /// Step through a recorded game
+    Replay(PathBuf),
+    /// Host a LAN game
+    Host(HostOptions),
+    /// Join a LAN game
+    Join(JoinOptions),
+    /// Watch a LAN game
+    Watch(JoinOptions),
+    /// Play games headless with a bot
+    Simulate(SimulateOptions),
+    /// Search a seeded solo deal for a winning line
+    Solve(SolveOptions),
+    /// Play a game over stdin/stdout for a bot in another process
+    BotServer(BotServerOptions),
+}
+
+/// Options for interactive play
+pub struct Options {
+    pub players: usize,    // --players <1-4>
+    pub seed: Option<u64>, // --seed <u64>
+    pub resume: bool,      // --resume
+    pub free_undo: bool,   // --free-undo: undo without marking the game assisted
+    pub strict_chat: bool, // --strict-chat: hot-seat chat allows only signals
+    pub bots: usize,       // --bots <n>: bots in the last n seats
+    pub bot: String,       // --bot <name>, one of `BOT_NAMES`
+}
+
+/// Options for hosting a LAN game
+pub struct HostOptions {
+    pub port: u16,            // --port <port>
+    pub name: Option<String>, // --name <name>
+    pub seed: Option<u64>,    // --seed <u64>
+    pub show_hands: bool,     // --show-hands: spectators see every hand
+    pub strict_chat: bool,    // --strict-chat: start the lobby with signals-only chat
+    pub bots: usize,          // --bots <n>: bots seated in the lobby
+    pub bot: String,          // --bot <name>, one of `BOT_NAMES`
+}
+
+/// Options for joining a LAN game
+pub struct JoinOptions {
+    pub addr: String,          // host:port, port defaulting to DEFAULT_PORT
+    pub name: Option<String>,  // --name <name>
+    pub token: Option<String>, // --token <token>: rejoin a game after a drop
+}
+
+/// Options for a headless simulation
+pub struct SimulateOptions {
+    pub games: usize,      // --games <n>
+    pub bot: String,       // --bot <name>, one of `BOT_NAMES`
+    pub seed: Option<u64>, // --seed <u64>: seed of the first game
+    pub players: usize,    // --players <1-4>
+    pub budget: Budget,    // --iterations <n> and --think-ms <ms> per decision
+}
+
+/// Options for solving a seeded deal
+pub struct SolveOptions {
+    pub seed: u64,             // --seed <u64>
+    pub nodes: usize,          // --nodes <n>: positions to search before giving up
+    pub save: Option<PathBuf>, // --save <file>: write the winning line as a replay
+}
+
+/// Options for serving a game to a bot over stdin/stdout
+pub struct BotServerOptions {
+    pub players: usize,    // --players <1-4>
+    pub seed: Option<u64>, // --seed <u64>
+}
+
+/// Parse the value following `flag`
+fn value<T: FromStr, I: Iterator<Item = String>>(flag: &str, args: &mut I) -> Result<T, String> {
+    let value = args
+        .next()
+        .ok_or_else(|| format!("{} requires a value", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, value))
+}
+
+/// Parse a player count, checking it is within `range`
+fn player_count<I: Iterator<Item = String>>(
+    args: &mut I,
+    range: std::ops::RangeInclusive<usize>,
+) -> Result<usize, String> {
+    let count: usize = value("--players", args)?;
+    if !range.contains(&count) {
+        return Err(format!(
+            "Invalid player count: {} ({}-{})",
+            count,
+            range.start(),
+            range.end()
+        ));
+    }
+    Ok(count)
+}
+
+/// Check that `name` is one of `BOT_NAMES`
+fn check_bot(name: &str) -> Result<(), String> {
+    if !BOT_NAMES.contains(&name) {
+        return Err(format!(
+            "Unknown bot: {} (one of {})",
+            name,
+            BOT_NAMES.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Parse command line arguments (without the program name)
+pub fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut options = Options {
+        players: 1,
+        seed: None,
+        resume: false,
+        free_undo: false,
+        strict_chat: false,
+        bots: 0,
+        bot: BOT_NAMES[0].to_string(),
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "replay" => {
+                let path = args.next().ok_or("replay requires a file")?;
+                if let Some(extra) = args.next() {
+                    return Err(format!("Unknown argument: {}", extra));
+                }
+                return Ok(Command::Replay(PathBuf::from(path)));
+            }
+            "host" => return parse_host(args),
+            "simulate" => return parse_simulate(args),
+            "solve" => return parse_solve(args),
+            "bot-server" => return parse_bot_server(args),
+            "join" => return parse_join(args).map(Command::Join),
+            "watch" => {
+                let options = parse_join(args)?;
+                if options.token.is_some() {
+                    return Err("--token is only for join".to_string());
+                }
+                return Ok(Command::Watch(options));
+            }
+            "--players" => options.players = player_count(&mut args, 1..=4)?,
+            "--seed" => options.seed = Some(value("--seed", &mut args)?),
+            "--resume" => options.resume = true,
+            "--free-undo" => options.free_undo = true,
+            "--strict-chat" => options.strict_chat = true,
+            "--bots" => options.bots = value("--bots", &mut args)?,
+            "--bot" => options.bot = value("--bot", &mut args)?,
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    // Someone has to be at the terminal
+    if options.bots >= options.players {
+        return Err(format!(
+            "Too many bots: {} for {} player(s), leave a seat for a person",
+            options.bots, options.players
+        ));
+    }
+    check_bot(&options.bot)?;
+    Ok(Command::Play(options))
+}
+
+fn parse_host<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut options = HostOptions {
+        port: DEFAULT_PORT,
+        name: None,
+        seed: None,
+        show_hands: false,
+        strict_chat: false,
+        bots: 0,
+        bot: BOT_NAMES[0].to_string(),
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--port" => options.port = value("--port", &mut args)?,
+            "--name" => options.name = Some(value("--name", &mut args)?),
+            "--seed" => options.seed = Some(value("--seed", &mut args)?),
+            "--show-hands" => options.show_hands = true,
+            "--strict-chat" => options.strict_chat = true,
+            "--bots" => options.bots = value("--bots", &mut args)?,
+            "--bot" => options.bot = value("--bot", &mut args)?,
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    // The host takes one of the four seats
+    if options.bots > 3 {
+        return Err(format!("Too many bots: {} (0-3)", options.bots));
+    }
+    check_bot(&options.bot)?;
+    Ok(Command::Host(options))
+}
+
+fn parse_simulate<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut options = SimulateOptions {
+        games: 1000,
+        bot: BOT_NAMES[0].to_string(),
+        seed: None,
+        players: 1,
+        budget: Budget::default(),
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--games" => options.games = value("--games", &mut args)?,
+            "--bot" => options.bot = value("--bot", &mut args)?,
+            "--seed" => options.seed = Some(value("--seed", &mut args)?),
+            "--players" => options.players = player_count(&mut args, 1..=4)?,
+            "--iterations" => {
+                options.budget.iterations = value("--iterations", &mut args)?;
+                if options.budget.iterations == 0 {
+                    return Err("--iterations must be at least 1".to_string());
+                }
+            }
+            "--think-ms" => {
+                let ms = value("--think-ms", &mut args)?;
+                options.budget.time = Some(Duration::from_millis(ms));
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    check_bot(&options.bot)?;
+    Ok(Command::Simulate(options))
+}
+
+fn parse_solve<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut seed = None;
+    let mut nodes = DEFAULT_NODE_LIMIT;
+    let mut save = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = Some(value("--seed", &mut args)?),
+            "--nodes" => nodes = value("--nodes", &mut args)?,
+            "--save" => save = Some(value::<String, _>("--save", &mut args)?.into()),
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    let seed = seed.ok_or("solve requires --seed")?;
+    Ok(Command::Solve(SolveOptions { seed, nodes, save }))
+}
+
+fn parse_bot_server<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut options = BotServerOptions {
+        players: 1,
+        seed: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--players" => options.players = player_count(&mut args, 1..=4)?,
+            "--seed" => options.seed = Some(value("--seed", &mut args)?),
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    Ok(Command::BotServer(options))
+}
+
+fn parse_join<I: Iterator<Item = String>>(mut args: I) -> Result<JoinOptions, String> {
+    let addr = args.next().ok_or("a host address is required")?;
+    // Allow a bare host name or IP, using the default port
+    let addr = if addr.contains(':') {
+        addr
+    } else {
+        format!("{}:{}", addr, DEFAULT_PORT)
+    };
+    let mut options = JoinOptions {
+        addr,
+        name: None,
+        token: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--name" => options.name = Some(value("--name", &mut args)?),
+            "--token" => options.token = Some(value("--token", &mut args)?),
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    Ok(options)
+}
@@ -0,0 +1,457 @@
+use crate::chat_box::{ChatBox, ChatInput};
+use crate::ui;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use kingslayer::net::{self, Client, Lobby, ServerMessage};
+use kingslayer::{
+    advisor, chat, ChatMessage, ChatMode, GameAction, GameView, Hint, Phase, RuleError,
+};
+use ratatui::Terminal;
+use std::io;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// How long to wait between attempts to rejoin after the connection drops
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A player's terminal in a LAN game: renders the host's view and sends actions back
+pub struct ClientApp {
+    client: Client,
+    address: String,      // Where the host is, to show in the lobby
+    spectator: bool,      // Watching without a seat
+    lobby: Option<Lobby>, // Latest lobby from the host, until the game starts
+    ready: bool,
+    view: Option<GameView>, // Latest state from the host; None until the game starts
+    selected_cards: Vec<usize>,
+    error_cards: Vec<usize>, // Cards flagged by the host's last rejection
+    notice: Option<String>,  // Last rejection or error from the host
+    hint: Option<Hint>,      // Advice shown after pressing '?', until the game moves on
+    player_cursor: usize,
+    log_scroll_offset: usize,
+    show_help: bool,
+    help_scroll_offset: usize,
+    quit_confirmation: bool,
+    disconnected: bool,
+    waiting_for: Vec<usize>, // Seats the host is waiting on to reconnect
+    reconnect: Option<JoinHandle<io::Result<Client>>>, // Rejoin attempt in progress
+    next_reconnect: Instant,
+    chat: Vec<ChatMessage>, // Everything said at the table, as relayed by the host
+    chat_box: Option<ChatBox>, // Message being written
+    chat_error: Option<String>, // Why the last message was blocked
+}
+
+impl ClientApp {
+    pub fn new(client: Client, address: String, spectator: bool) -> Self {
+        Self {
+            client,
+            address,
+            spectator,
+            lobby: None,
+            ready: false,
+            view: None,
+            selected_cards: Vec::new(),
+            error_cards: Vec::new(),
+            notice: None,
+            hint: None,
+            player_cursor: 0,
+            log_scroll_offset: 0,
+            show_help: false,
+            help_scroll_offset: 0,
+            quit_confirmation: false,
+            disconnected: false,
+            waiting_for: Vec::new(),
+            reconnect: None,
+            next_reconnect: Instant::now(),
+            chat: Vec::new(),
+            chat_box: None,
+            chat_error: None,
+        }
+    }
+
+    /// Take in everything the host has sent since the last frame
+    fn poll_host(&mut self) {
+        loop {
+            match self.client.try_recv() {
+                Ok(Some(ServerMessage::Lobby { lobby })) => self.lobby = Some(lobby),
+                Ok(Some(ServerMessage::State { view })) => self.update(view),
+                Ok(Some(ServerMessage::Rejected { error })) => self.rejected(error),
+                Ok(Some(ServerMessage::Error { message })) => self.notice = Some(message),
+                Ok(Some(ServerMessage::Waiting { seats })) => self.waiting_for = seats,
+                Ok(Some(ServerMessage::Chat { message })) => self.chat.push(message),
+                Ok(Some(ServerMessage::ChatRejected { error })) => {
+                    self.chat_error = Some(error.to_string());
+                }
+                Ok(Some(ServerMessage::Welcome { .. } | ServerMessage::Ping)) => {}
+                Ok(None) => break,
+                Err(_) => {
+                    self.disconnected = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Keep trying to take our seat back while the connection is down. Only a
+    /// started game holds seats open; a dropped lobby place is simply gone.
+    /// Spectators just start watching again.
+    fn try_reconnect(&mut self) {
+        if !self.disconnected || (self.view.is_none() && !self.spectator) {
+            return;
+        }
+
+        match self.reconnect.take() {
+            Some(attempt) if attempt.is_finished() => match attempt.join() {
+                Ok(Ok(client)) => {
+                    // The host sends the chat history again
+                    self.chat.clear();
+                    self.client = client;
+                    self.disconnected = false;
+                    self.notice = Some("Reconnected".to_string());
+                }
+                _ => self.next_reconnect = Instant::now() + RECONNECT_INTERVAL,
+            },
+            Some(attempt) => self.reconnect = Some(attempt),
+            None if Instant::now() >= self.next_reconnect => {
+                let address = self.address.clone();
+                let token = self.client.token.clone();
+                let name = self.client.name.clone();
+                let spectator = self.spectator;
+                self.reconnect = Some(thread::spawn(move || {
+                    if spectator {
+                        Client::watch(address, &name)
+                    } else {
+                        Client::rejoin(address, &token)
+                    }
+                }));
+            }
+            None => {}
+        }
+    }
+
+    fn update(&mut self, view: GameView) {
+        let moved_on = self
+            .view
+            .as_ref()
+            .is_none_or(|old| old.current_player != view.current_player || old.phase != view.phase);
+        if moved_on {
+            self.selected_cards.clear();
+            self.error_cards.clear();
+            self.notice = None;
+        }
+        if moved_on
+            || self
+                .view
+                .as_ref()
+                .is_some_and(|old| old.hand() != view.hand())
+        {
+            self.hint = None;
+        }
+        // Default the Jester choice to whoever is next around the table
+        if view.phase == Phase::ChooseNextPlayer {
+            self.player_cursor = (view.current_player + 1) % view.players.len();
+        }
+        self.view = Some(view);
+        self.log_scroll_offset = 0;
+    }
+
+    fn rejected(&mut self, error: RuleError) {
+        self.error_cards = match error {
+            RuleError::InvalidIndex(index) => vec![index],
+            e if e.concerns_selection() => self.selected_cards.clone(),
+            _ => Vec::new(),
+        };
+        self.notice = Some(format!("Error: {}", error));
+    }
+
+    /// The table's chat rules, from the lobby or from when the host let us in
+    fn chat_mode(&self) -> ChatMode {
+        self.lobby
+            .as_ref()
+            .map_or(self.client.settings, |lobby| lobby.settings)
+            .chat_mode
+    }
+
+    fn chat_panel(&self) -> ui::ChatPanel<'_> {
+        ui::ChatPanel {
+            messages: &self.chat,
+            mode: self.chat_mode(),
+            chat_box: self.chat_box.as_ref(),
+            error: self.chat_error.as_deref(),
+        }
+    }
+
+    /// Send a chat message if it keeps to the table's rules; the host has the
+    /// final say and sends it back to everyone, us included
+    fn send_chat(&mut self, text: &str) {
+        let seats = self.view.as_ref().map_or(0, |view| view.players.len());
+        match chat::check_message(self.chat_mode(), seats, text) {
+            Ok(text) => {
+                if self.client.chat(&text).is_err() {
+                    self.disconnected = true;
+                }
+                self.chat_box = None;
+                self.chat_error = None;
+            }
+            Err(e) => self.chat_error = Some(e.to_string()),
+        }
+    }
+
+    fn toggle_ready(&mut self) {
+        self.ready = !self.ready;
+        if self.client.set_ready(self.ready).is_err() {
+            self.disconnected = true;
+        }
+    }
+
+    fn lobby_prompt(&self) -> String {
+        if self.quit_confirmation {
+            "Leave the game? Press 'y' to confirm or 'n' to cancel".to_string()
+        } else if self.disconnected {
+            "Disconnected from the host. Press 'q' to quit".to_string()
+        } else if self.spectator {
+            "Watching. Waiting for the host to start the game... 'q' to quit".to_string()
+        } else if self.ready {
+            "Ready! Waiting for the host to start... 'r' to cancel, 'q' to quit".to_string()
+        } else {
+            "Press 'r' when you are ready to play, or 'q' to quit".to_string()
+        }
+    }
+
+    fn send(&mut self, action: GameAction) {
+        self.notice = None;
+        if self.client.send(action).is_err() {
+            self.disconnected = true;
+        }
+    }
+
+    fn toggle_card_selection(&mut self, index: usize) {
+        let hand_size = self.view.as_ref().map_or(0, |view| view.hand().len());
+        if index >= hand_size {
+            return;
+        }
+
+        self.error_cards.clear();
+        if let Some(pos) = self.selected_cards.iter().position(|&i| i == index) {
+            self.selected_cards.remove(pos);
+        } else {
+            self.selected_cards.push(index);
+        }
+    }
+
+    /// Select the least wasteful cards that cover the enemy attack
+    fn select_suggested_discard(&mut self) {
+        let Some(view) = &self.view else {
+            return;
+        };
+        if !matches!(view.phase, Phase::Discard { .. }) || !view.is_my_turn() {
+            return;
+        }
+        match view.suggested_discard() {
+            Some(cards) => {
+                self.selected_cards = cards;
+                self.error_cards.clear();
+            }
+            None => self.notice = Some("No discard covers the attack".to_string()),
+        }
+    }
+
+    /// Ask the advisor for the best move, highlighting its cards in the hand
+    fn show_hint(&mut self) {
+        let Some(view) = &self.view else {
+            return;
+        };
+        self.hint = advisor::hint(view);
+        if self.hint.is_none() {
+            self.notice = Some("No hint right now".to_string());
+        }
+    }
+
+    fn send_selected_cards(&mut self, phase: Phase) {
+        self.selected_cards.sort_unstable();
+        let cards = self.selected_cards.clone();
+        match phase {
+            Phase::Play => self.send(GameAction::Play(cards)),
+            Phase::Discard { .. } => self.send(GameAction::Discard(cards)),
+            Phase::ChooseNextPlayer => {}
+        }
+    }
+
+    fn action_prompt(&self, view: &GameView) -> String {
+        if self.quit_confirmation {
+            return "Leave the game? Press 'y' to confirm or 'n' to cancel".to_string();
+        }
+        if self.disconnected {
+            if self.spectator {
+                return "Connection lost, reconnecting... Press 'q' to quit".to_string();
+            }
+            return format!(
+                "Connection lost, reconnecting... Press 'q' to quit (rejoin later with: kingslayer join {} --token {})",
+                self.address, self.client.token
+            );
+        }
+        if !self.waiting_for.is_empty() {
+            let names: Vec<&str> = self
+                .waiting_for
+                .iter()
+                .filter_map(|&seat| view.players.get(seat))
+                .map(|player| player.name.as_str())
+                .collect();
+            return net::reconnect_notice(&names);
+        }
+        if view.is_over() {
+            return "Game over. Waiting for the host to restart, or press 'q' to quit".to_string();
+        }
+        if self.spectator {
+            return format!("👀 Spectating | {} | 'q' to quit", ui::turn_prompt(view));
+        }
+        match &self.notice {
+            Some(notice) => format!("{} | {}", notice, ui::turn_prompt(view)),
+            None => ui::turn_prompt(view),
+        }
+    }
+}
+
+pub fn run<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut ClientApp,
+) -> io::Result<()> {
+    loop {
+        terminal.draw(|f| {
+            if app.show_help {
+                ui::render_help(f, app.help_scroll_offset);
+                return;
+            }
+
+            let Some(view) = &app.view else {
+                let prompt = app.lobby_prompt();
+                match &app.lobby {
+                    Some(lobby) => {
+                        let heading = format!("Connected to {}", app.address);
+                        ui::render_lobby(f, lobby, &heading, None, &prompt);
+                    }
+                    None => {
+                        let heading = format!("Joined as {}", app.client.name);
+                        ui::render_notice(f, "🌐 LAN Game 🌐", &heading, &prompt);
+                    }
+                }
+                return;
+            };
+
+            let action_prompt = app.action_prompt(view);
+            ui::render_game(
+                f,
+                view,
+                ui::HandMarks {
+                    selected: &app.selected_cards,
+                    errors: &app.error_cards,
+                    hint: app.hint.as_ref(),
+                },
+                app.log_scroll_offset,
+                ui::InfoPane::Chat(app.chat_panel()),
+                &action_prompt,
+            );
+            if view.phase == Phase::ChooseNextPlayer && view.is_my_turn() && !view.is_over() {
+                ui::render_player_choice(f, view, app.player_cursor);
+            }
+        })?;
+
+        app.poll_host();
+        app.try_reconnect();
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        if let Some(chat_box) = &mut app.chat_box {
+            match chat_box.handle_key(key.code) {
+                ChatInput::Editing => {}
+                ChatInput::Send(text) => app.send_chat(&text),
+                ChatInput::Cancel => {
+                    app.chat_box = None;
+                    app.chat_error = None;
+                }
+            }
+            continue;
+        }
+
+        if app.quit_confirmation {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => return Ok(()),
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                    app.quit_confirmation = false;
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        let choosing = app.view.as_ref().is_some_and(|view| {
+            view.phase == Phase::ChooseNextPlayer && view.is_my_turn() && !view.is_over()
+        });
+        let players = app.view.as_ref().map_or(1, |view| view.players.len());
+
+        match key.code {
+            KeyCode::Char('q') if app.disconnected => return Ok(()),
+            KeyCode::Char('q') => app.quit_confirmation = true,
+            KeyCode::Char('h') => {
+                app.show_help = !app.show_help;
+                app.help_scroll_offset = 0;
+            }
+            KeyCode::Up if app.show_help => {
+                app.help_scroll_offset = app.help_scroll_offset.saturating_sub(1);
+            }
+            KeyCode::Down if app.show_help => {
+                let max_scroll = ui::get_help_line_count().saturating_sub(10);
+                app.help_scroll_offset = (app.help_scroll_offset + 1).min(max_scroll);
+            }
+            _ if app.show_help => {}
+            KeyCode::Char('r') if app.view.is_none() && !app.spectator => app.toggle_ready(),
+            KeyCode::Up if choosing => {
+                app.player_cursor = (app.player_cursor + players - 1) % players
+            }
+            KeyCode::Down if choosing => app.player_cursor = (app.player_cursor + 1) % players,
+            KeyCode::Up => {
+                let log_len = app.view.as_ref().map_or(0, |view| view.game_log.len());
+                if app.log_scroll_offset < log_len {
+                    app.log_scroll_offset += 1;
+                }
+            }
+            KeyCode::Down => app.log_scroll_offset = app.log_scroll_offset.saturating_sub(1),
+            _ if app.spectator => {}
+            KeyCode::Char('c') if app.view.is_some() && !app.disconnected => {
+                app.chat_box = Some(ChatBox::open(app.chat_mode()));
+                app.chat_error = None;
+            }
+            KeyCode::Char(c) if choosing && c.is_ascii_digit() => {
+                let digit = c.to_digit(10).unwrap() as usize;
+                if digit >= 1 {
+                    app.send(GameAction::ChooseNextPlayer(digit - 1));
+                }
+            }
+            KeyCode::Enter if choosing => {
+                app.send(GameAction::ChooseNextPlayer(app.player_cursor));
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                let digit = c.to_digit(10).unwrap() as usize;
+                // Convert 1-8 to indices 0-7 (1-based numbering for user)
+                if (1..=8).contains(&digit) {
+                    app.toggle_card_selection(digit - 1);
+                }
+            }
+            KeyCode::Enter => {
+                if let Some(phase) = app.view.as_ref().map(|view| view.phase) {
+                    app.send_selected_cards(phase);
+                }
+            }
+            KeyCode::Char(' ') => app.send(GameAction::Yield),
+            KeyCode::Char('a') => app.select_suggested_discard(),
+            KeyCode::Char('?') => app.show_hint(),
+            _ => {}
+        }
+    }
+}
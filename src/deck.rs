@@ -1,21 +1,26 @@
 use crate::card::{Card, Rank, Suit};
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Deck {
     pub cards: Vec<Card>,
+    #[serde(default)]
+    pub face_up: usize, // Cards on top that every player has seen, such as captured enemies
 }
 
 impl Deck {
     /// Create a new empty deck
     pub fn new() -> Self {
-        Self { cards: Vec::new() }
+        Self {
+            cards: Vec::new(),
+            face_up: 0,
+        }
     }
 
     /// Create the Tavern deck (player deck) with specified number of Jesters
-    pub fn create_tavern_deck(jester_count: u8) -> Self {
+    pub fn create_tavern_deck<R: Rng + ?Sized>(jester_count: u8, rng: &mut R) -> Self {
         let mut cards = Vec::new();
 
         // Add all numbered cards (2-10) in all suits
@@ -45,45 +50,44 @@ impl Deck {
             cards.push(Card::new(Suit::Hearts, Rank::Jester)); // Jesters don't have suits, but we use Hearts
         }
 
-        let mut deck = Self { cards };
-        deck.shuffle();
+        let mut deck = Self { cards, face_up: 0 };
+        deck.shuffle(rng);
         deck
     }
 
     /// Create the Castle deck (enemy deck) with proper layering:
     /// 4 Kings (bottom), 4 Queens (middle), 4 Jacks (top)
     /// Suits within each layer are randomized
-    pub fn create_castle_deck() -> Self {
+    pub fn create_castle_deck<R: Rng + ?Sized>(rng: &mut R) -> Self {
         let mut cards = Vec::new();
         let suits = [Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades];
-        let mut rng = thread_rng();
 
         // Create Kings (bottom layer) - added first so they're at start of vector
         let mut kings: Vec<Card> = suits.iter().map(|&s| Card::new(s, Rank::King)).collect();
-        kings.shuffle(&mut rng);
+        kings.shuffle(rng);
         cards.extend(kings);
 
         // Create Queens (middle layer)
         let mut queens: Vec<Card> = suits.iter().map(|&s| Card::new(s, Rank::Queen)).collect();
-        queens.shuffle(&mut rng);
+        queens.shuffle(rng);
         cards.extend(queens);
 
         // Create Jacks (top layer) - added last so they're at end of vector and drawn first via pop()
         let mut jacks: Vec<Card> = suits.iter().map(|&s| Card::new(s, Rank::Jack)).collect();
-        jacks.shuffle(&mut rng);
+        jacks.shuffle(rng);
         cards.extend(jacks);
 
-        Self { cards }
+        Self { cards, face_up: 0 }
     }
 
     /// Shuffle the deck
-    pub fn shuffle(&mut self) {
-        let mut rng = thread_rng();
-        self.cards.shuffle(&mut rng);
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
     }
 
     /// Draw a card from the top of the deck
     pub fn draw(&mut self) -> Option<Card> {
+        self.face_up = self.face_up.saturating_sub(1);
         self.cards.pop()
     }
 
@@ -100,9 +104,16 @@ impl Deck {
         drawn
     }
 
-    /// Add a card to the top of the deck
+    /// Add a card face up to the top of the deck, so everyone knows it comes next
     pub fn add_to_top(&mut self, card: Card) {
         self.cards.push(card);
+        self.face_up += 1;
+    }
+
+    /// The cards on top that every player has seen, the next one drawn last
+    pub fn face_up_cards(&self) -> &[Card] {
+        let face_up = self.face_up.min(self.cards.len());
+        &self.cards[self.cards.len() - face_up..]
     }
 
     /// Add multiple cards to the bottom of the deck
@@ -116,6 +127,11 @@ impl Deck {
     pub fn len(&self) -> usize {
         self.cards.len()
     }
+
+    /// Check if the deck has run out of cards
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
 }
 
 impl Default for Deck {
@@ -59,21 +59,7 @@ impl Enemy {
     }
 
     pub fn name(&self) -> String {
-        format!(
-            "{} of {}",
-            match self.card.rank {
-                Rank::Jack => "Jack",
-                Rank::Queen => "Queen",
-                Rank::King => "King",
-                _ => "Unknown",
-            },
-            match self.card.suit {
-                Suit::Hearts => "Hearts",
-                Suit::Diamonds => "Diamonds",
-                Suit::Clubs => "Clubs",
-                Suit::Spades => "Spades",
-            }
-        )
+        self.card.name()
     }
 
     /// Returns the HP bar as a visual representation
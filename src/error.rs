@@ -0,0 +1,131 @@
+use crate::game::Phase;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Why a `GameAction` was rejected by the rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleError {
+    /// No cards were selected
+    EmptySelection,
+    /// A selected index is out of range or selected twice
+    InvalidIndex(usize),
+    /// A Jester was combined with other cards
+    JesterNotAlone,
+    /// An Ace was combined with more than one other card
+    AceWithTooMany,
+    /// More than 4 cards were played at once
+    TooManyCards,
+    /// A combo mixed ranks without an Ace
+    MixedRankCombo,
+    /// A same-rank combo added up to more than 10
+    ComboOverTen { total: u8 },
+    /// The discarded cards do not cover the enemy attack
+    NotEnoughDiscardValue { need: u8, have: u8 },
+    /// Every solo Jester power has been used
+    NoJestersLeft,
+    /// The chosen player does not exist
+    InvalidPlayer(usize),
+    /// Someone other than the current player tried to act
+    NotYourTurn { current: usize },
+    /// Jester powers can only be used in solo games
+    SoloOnly,
+    /// Every other player yielded on their last turn, so this one must play
+    EveryoneElseYielded,
+    /// The action does not fit the current phase of the turn
+    WrongPhase { phase: Phase },
+    /// The game has already been won or lost
+    GameOver,
+    /// There is no enemy to fight
+    NoEnemy,
+}
+
+impl RuleError {
+    /// Check if the error is about which cards were selected
+    pub fn concerns_selection(&self) -> bool {
+        matches!(
+            self,
+            RuleError::InvalidIndex(_)
+                | RuleError::JesterNotAlone
+                | RuleError::AceWithTooMany
+                | RuleError::TooManyCards
+                | RuleError::MixedRankCombo
+                | RuleError::ComboOverTen { .. }
+                | RuleError::NotEnoughDiscardValue { .. }
+        )
+    }
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleError::EmptySelection => write!(f, "Must select at least one card"),
+            RuleError::InvalidIndex(index) => write!(f, "Invalid card index {}", index + 1),
+            RuleError::JesterNotAlone => write!(f, "Jester must be played alone"),
+            RuleError::AceWithTooMany => {
+                write!(f, "Ace can only be paired with one other card")
+            }
+            RuleError::TooManyCards => write!(f, "Cannot play more than 4 cards at once"),
+            RuleError::MixedRankCombo => write!(
+                f,
+                "Combo cards must all have the same rank (or use Ace + 1 card)"
+            ),
+            RuleError::ComboOverTen { total } => {
+                write!(f, "Combo total must be 10 or less (was {})", total)
+            }
+            RuleError::NotEnoughDiscardValue { need, have } => {
+                write!(f, "Not enough value (need {}, have {})", need, have)
+            }
+            RuleError::NoJestersLeft => write!(f, "No Jesters remaining"),
+            RuleError::InvalidPlayer(player) => write!(f, "There is no Player {}", player + 1),
+            RuleError::NotYourTurn { current } => {
+                write!(f, "It is Player {}'s turn", current + 1)
+            }
+            RuleError::SoloOnly => write!(f, "Jester powers are only available in solo games"),
+            RuleError::EveryoneElseYielded => write!(
+                f,
+                "Cannot yield: every other player yielded on their last turn"
+            ),
+            RuleError::WrongPhase { phase } => match phase {
+                Phase::Play => write!(f, "Play cards or yield first"),
+                Phase::Discard { required } => write!(
+                    f,
+                    "Must discard cards worth {} to survive the enemy attack first",
+                    required
+                ),
+                Phase::ChooseNextPlayer => write!(f, "Choose who goes next first"),
+            },
+            RuleError::GameOver => write!(f, "The game is over"),
+            RuleError::NoEnemy => write!(f, "No current enemy"),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+/// Why a chat message was blocked by Regicide's communication rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChatError {
+    /// The message was blank
+    Empty,
+    /// The message was longer than the chat allows
+    TooLong { max: usize },
+    /// Strict chat only allows the fixed signals
+    NotASignal,
+    /// The message names a card rank or suit, which could reveal a hand
+    MentionsCards,
+}
+
+impl fmt::Display for ChatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChatError::Empty => write!(f, "Nothing to send"),
+            ChatError::TooLong { max } => write!(f, "Messages can be at most {} characters", max),
+            ChatError::NotASignal => write!(f, "Strict chat only allows the listed signals"),
+            ChatError::MentionsCards => {
+                write!(f, "Messages can't mention card ranks or suits")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChatError {}
@@ -0,0 +1,131 @@
+use crate::card::{Card, Suit};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A fact emitted by a rule step; the text game log is rendered from these
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// A new game was dealt from this seed
+    GameStarted { seed: u64 },
+    /// The next enemy was revealed from the castle deck
+    EnemyRevealed { enemy: Card },
+    /// Cards were played against the enemy (Step 1)
+    CardsPlayed { cards: Vec<Card>, attack: u8 },
+    /// A Jester was played, cancelling the enemy's immunity
+    JesterPlayed,
+    /// A suit power was blocked by the enemy's immunity
+    PowerBlocked { suit: Suit },
+    /// Hearts moved cards from the discard pile to the bottom of the tavern deck
+    Healed { count: usize },
+    /// Diamonds drew cards into the player's hand
+    Drew { count: usize },
+    /// Clubs doubled this turn's damage
+    DamageDoubled,
+    /// Spades raised the shield; `retroactive` when a Jester unblocked earlier Spades
+    ShieldRaised {
+        amount: u8,
+        total: u8,
+        retroactive: bool,
+    },
+    /// Damage was dealt to the enemy (Step 3)
+    DamageDealt { damage: u8, total: u8, max_hp: u8 },
+    /// The enemy was defeated with exact damage and placed on the tavern deck
+    EnemyCaptured { enemy: Card },
+    /// The enemy was defeated by overkill and placed on the discard pile
+    EnemyDefeated { enemy: Card },
+    /// The player skipped straight to the enemy attack
+    Yielded,
+    /// The enemy attacked for this much damage after shields (Step 4)
+    EnemyAttack { damage: u8 },
+    /// Cards were discarded to absorb the enemy attack
+    Discarded { cards: Vec<Card>, value: u8 },
+    /// The player discarded enough to survive and a new turn begins
+    AttackSurvived,
+    /// Solo mode: the hand was replaced using a Jester power
+    JesterUsed { discarded: usize, remaining: u8 },
+    /// Multiplayer: the turn passed to the player at this index
+    TurnPassed { player: usize },
+    /// Multiplayer: after a Jester, the player at this index was chosen to go next
+    NextPlayerChosen { player: usize },
+    /// All 12 enemies are defeated
+    Victory,
+    /// The game was lost
+    Defeat { reason: String },
+}
+
+/// Join cards into a comma separated list for log messages
+fn card_list(cards: &[Card]) -> String {
+    let names: Vec<String> = cards.iter().map(|c| c.display()).collect();
+    names.join(", ")
+}
+
+impl fmt::Display for GameEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            // The seed reveals the deal, so it stays out of the log that views share
+            GameEvent::GameStarted { .. } => {
+                write!(f, "Game started! Defeat all 12 enemies to win.")
+            }
+            GameEvent::EnemyRevealed { enemy } => write!(f, "A {} appears!", enemy.name()),
+            GameEvent::CardsPlayed { cards, attack } => {
+                write!(f, "Played: {} (Attack: {})", card_list(cards), attack)
+            }
+            GameEvent::JesterPlayed => write!(f, "Played Jester - Enemy immunity cancelled!"),
+            GameEvent::PowerBlocked { suit: Suit::Clubs } => {
+                write!(f, "Clubs power blocked by immunity (double damage negated)")
+            }
+            GameEvent::PowerBlocked { suit } => {
+                write!(f, "{} power blocked by immunity", suit.name())
+            }
+            GameEvent::Healed { count } => {
+                write!(f, "Healed {} cards from discard to tavern deck", count)
+            }
+            GameEvent::Drew { count } => write!(f, "Drew {} cards", count),
+            GameEvent::DamageDoubled => write!(f, "Clubs active - double damage!"),
+            GameEvent::ShieldRaised {
+                amount,
+                total,
+                retroactive,
+            } => {
+                if *retroactive {
+                    write!(f, "Spades now active! ")?;
+                }
+                write!(f, "Shield increased by {} (Total: {})", amount, total)
+            }
+            GameEvent::DamageDealt {
+                damage,
+                total,
+                max_hp,
+            } => write!(f, "Dealt {} damage (Total: {}/{})", damage, total, max_hp),
+            GameEvent::EnemyCaptured { enemy } => {
+                write!(f, "Exact damage! {} captured!", enemy.name())
+            }
+            GameEvent::EnemyDefeated { enemy } => write!(f, "{} defeated!", enemy.name()),
+            GameEvent::Yielded => write!(f, "Yielded turn"),
+            GameEvent::EnemyAttack { damage: 0 } => {
+                write!(f, "Enemy attack fully blocked by shields!")
+            }
+            GameEvent::EnemyAttack { damage } => {
+                write!(f, "Enemy attacks for {} damage!", damage)
+            }
+            GameEvent::Discarded { cards, value } => {
+                write!(f, "Discarded: {} (Value: {})", card_list(cards), value)
+            }
+            GameEvent::AttackSurvived => write!(f, "Survived enemy attack! New turn begins."),
+            GameEvent::JesterUsed {
+                discarded,
+                remaining,
+            } => write!(
+                f,
+                "Used Jester power! Discarded {} cards and drew fresh hand ({} Jesters remaining)",
+                discarded, remaining
+            ),
+            GameEvent::TurnPassed { player } => write!(f, "Player {}'s turn", player + 1),
+            GameEvent::NextPlayerChosen { player } => {
+                write!(f, "Player {} was chosen to go next", player + 1)
+            }
+            GameEvent::Victory => write!(f, "Victory! All enemies have been defeated!"),
+            GameEvent::Defeat { reason } => write!(f, "Defeat! {}", reason),
+        }
+    }
+}
@@ -1,10 +1,19 @@
+use crate::action::GameAction;
 use crate::card::{Card, Suit};
 use crate::deck::Deck;
 use crate::enemy::Enemy;
+use crate::error::RuleError;
+use crate::event::GameEvent;
 use crate::player::Player;
 use chrono::Local;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 
+/// Seedable RNG used for every shuffle in a game, so a seed reproduces a deal card-for-card
+pub type GameRng = ChaCha8Rng;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GameState {
     Playing,
@@ -12,74 +21,247 @@ pub enum GameState {
     Defeat(String), // Reason for defeat
 }
 
+/// Where the current turn is waiting for input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Phase {
+    Play,                     // Step 1: play cards or yield
+    Discard { required: u8 }, // Step 4: discard to survive the enemy attack
+    ChooseNextPlayer,         // Multiplayer: a Jester was played, pick who goes next
+}
+
+/// Solo victory grade, by how many Jester powers were used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VictoryRank {
+    Gold,   // 0 Jesters used
+    Silver, // 1 Jester used
+    Bronze, // 2 Jesters used
+}
+
+impl std::fmt::Display for VictoryRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VictoryRank::Gold => write!(f, "Gold"),
+            VictoryRank::Silver => write!(f, "Silver"),
+            VictoryRank::Bronze => write!(f, "Bronze"),
+        }
+    }
+}
+
+/// Every set of cards in `hand` that may be played together, as sorted
+/// indices: any single card, an Ace with one other card (a Jester must be
+/// alone), or 2-4 cards of one rank worth 10 or less in total
+pub fn legal_plays_in(hand: &[Card]) -> Vec<Vec<usize>> {
+    let mut plays: Vec<Vec<usize>> = (0..hand.len()).map(|i| vec![i]).collect();
+
+    // Ace pairings, including Ace + Ace
+    for i in 0..hand.len() {
+        for j in i + 1..hand.len() {
+            let (a, b) = (&hand[i], &hand[j]);
+            if (a.is_companion() || b.is_companion()) && !a.is_jester() && !b.is_jester() {
+                plays.push(vec![i, j]);
+            }
+        }
+    }
+
+    // Same-rank combos; Aces and Jesters never combine beyond the pairs above
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for (i, card) in hand.iter().enumerate() {
+        if card.is_companion() || card.is_jester() {
+            continue;
+        }
+        match groups
+            .iter_mut()
+            .find(|group| hand[group[0]].rank == card.rank)
+        {
+            Some(group) => group.push(i),
+            None => groups.push(vec![i]),
+        }
+    }
+    for group in groups {
+        for mask in 1..1u32 << group.len() {
+            let combo: Vec<usize> = (0..group.len())
+                .filter(|bit| mask & (1 << bit) != 0)
+                .map(|bit| group[bit])
+                .collect();
+            let total: u32 = combo.iter().map(|&i| hand[i].value() as u32).sum();
+            if (2..=4).contains(&combo.len()) && total <= 10 {
+                plays.push(combo);
+            }
+        }
+    }
+
+    plays.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+    plays
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Game {
     pub castle_deck: Deck,
     pub tavern_deck: Deck,
     pub discard_pile: Vec<Card>,
     pub current_enemy: Option<Enemy>,
-    pub player: Player,
+    pub players: Vec<Player>,
+    pub current_player: usize, // Index into `players` of whose turn it is
     pub played_cards: Vec<Card>,
     pub shield_value: u8, // Cumulative shield from Spades
     pub total_damage: u8, // Total damage dealt to current enemy
     pub game_state: GameState,
+    pub phase: Phase,
     pub game_log: Vec<String>,
-    pub jester_count: u8,              // For solo mode
-    pub jesters_used: u8,              // For solo mode
+    pub events: Vec<GameEvent>, // Every event emitted since the game started
+    pub actions: Vec<GameAction>, // Every accepted action, for replays
+    pub jester_count: u8,       // Solo Jester powers (0 in multiplayer)
+    pub jesters_used: u8,       // For solo mode
+    #[serde(default)]
+    pub assisted: bool, // Actions were undone, so a victory is unranked
     pub jester_played_this_turn: bool, // Track if Jester was played to skip Step 4
+    #[serde(default)]
+    pub consecutive_yields: usize, // Yields in a row since anyone last played a card
+    pub seed: u64,              // Seed the game was dealt from
+    rng: GameRng,
 }
 
 impl Game {
-    /// Create a new solo game
+    /// Create a new solo game with a random seed
     pub fn new_solo() -> Self {
-        let mut tavern_deck = Deck::create_tavern_deck(0); // 0 Jesters for solo
-        let castle_deck = Deck::create_castle_deck();
+        Self::new(1)
+    }
+
+    /// Create a new solo game whose shuffles are all driven by `seed`
+    pub fn new_solo_with_seed(seed: u64) -> Self {
+        Self::new_with_seed(1, seed)
+    }
+
+    /// Create a new game for 1-4 players with a random seed
+    pub fn new(player_count: usize) -> Self {
+        Self::new_with_seed(player_count, rand::random())
+    }
+
+    /// Create a new game for 1-4 players whose shuffles are all driven by `seed`
+    ///
+    /// Panics if `player_count` is not between 1 and 4.
+    pub fn new_with_seed(player_count: usize, seed: u64) -> Self {
+        assert!(
+            (1..=4).contains(&player_count),
+            "Regicide is played by 1-4 players, not {}",
+            player_count
+        );
 
-        let mut player = Player::new("Hero".to_string(), 8);
+        // Hand size and tavern Jesters by player count
+        let (hand_size, tavern_jesters) = match player_count {
+            1 => (8, 0),
+            2 => (7, 0),
+            3 => (6, 1),
+            _ => (5, 2),
+        };
+
+        let mut rng = GameRng::seed_from_u64(seed);
+        let mut tavern_deck = Deck::create_tavern_deck(tavern_jesters, &mut rng);
+        let castle_deck = Deck::create_castle_deck(&mut rng);
 
-        // Draw initial hand
-        let initial_cards = tavern_deck.draw_multiple(8);
-        player.draw_multiple(initial_cards);
+        // Deal each player a full starting hand
+        let players = (0..player_count)
+            .map(|i| {
+                let name = if player_count == 1 {
+                    "Hero".to_string()
+                } else {
+                    format!("Player {}", i + 1)
+                };
+                let mut player = Player::new(name, hand_size);
+                player.draw_multiple(tavern_deck.draw_multiple(hand_size));
+                player
+            })
+            .collect();
 
         let mut game = Self {
             castle_deck,
             tavern_deck,
             discard_pile: Vec::new(),
             current_enemy: None,
-            player,
+            players,
+            current_player: 0,
             played_cards: Vec::new(),
             shield_value: 0,
             total_damage: 0,
             game_state: GameState::Playing,
+            phase: Phase::Play,
             game_log: Vec::new(),
-            jester_count: 2,
+            events: Vec::new(),
+            actions: Vec::new(),
+            jester_count: if player_count == 1 { 2 } else { 0 },
             jesters_used: 0,
+            assisted: false,
             jester_played_this_turn: false,
+            consecutive_yields: 0,
+            seed,
+            rng,
         };
 
         // Reveal first enemy
+        game.emit(GameEvent::GameStarted { seed });
         game.reveal_next_enemy();
-        game.log("Game started! Defeat all 12 enemies to win.");
 
         game
     }
 
+    /// Check if this is a solo game
+    pub fn is_solo(&self) -> bool {
+        self.players.len() == 1
+    }
+
+    /// Check if the solo Jester power is still available
+    pub fn can_use_solo_jester(&self) -> bool {
+        self.is_solo() && self.jesters_used < self.jester_count
+    }
+
+    /// The player whose turn it is
+    pub fn player(&self) -> &Player {
+        &self.players[self.current_player]
+    }
+
+    fn player_mut(&mut self) -> &mut Player {
+        &mut self.players[self.current_player]
+    }
+
+    /// Hand the turn to the next player (multiplayer only)
+    fn end_turn(&mut self) {
+        if self.is_solo() || self.is_over() {
+            return;
+        }
+        self.current_player = (self.current_player + 1) % self.players.len();
+        self.emit(GameEvent::TurnPassed {
+            player: self.current_player,
+        });
+    }
+
     /// Reveal the next enemy from the castle deck
     fn reveal_next_enemy(&mut self) {
         if let Some(card) = self.castle_deck.draw() {
-            let enemy = Enemy::new(card);
-            self.log(format!("A {} appears!", enemy.name()));
-            self.current_enemy = Some(enemy);
+            self.emit(GameEvent::EnemyRevealed { enemy: card });
+            self.current_enemy = Some(Enemy::new(card));
             self.shield_value = 0;
             self.total_damage = 0;
             self.played_cards.clear();
         } else {
             // No more enemies - Victory!
             self.game_state = GameState::Victory;
-            self.log("Victory! All enemies have been defeated!");
+            self.emit(GameEvent::Victory);
         }
     }
 
+    /// Record an event and render it into the text log
+    fn emit(&mut self, event: GameEvent) {
+        self.log(event.to_string());
+        self.events.push(event);
+    }
+
+    /// End the game in defeat
+    fn defeat<S: Into<String>>(&mut self, reason: S) {
+        let reason = reason.into();
+        self.game_state = GameState::Defeat(reason.clone());
+        self.emit(GameEvent::Defeat { reason });
+    }
+
     /// Add a message to the game log (limited to 100 entries)
     pub fn log<S: Into<String>>(&mut self, message: S) {
         let timestamp = Local::now().format("%H:%M:%S");
@@ -91,25 +273,285 @@ impl Game {
         }
     }
 
-    /// Validate if cards can be played together
-    pub fn validate_play(&self, card_indices: &[usize]) -> Result<(), String> {
+    /// Check if the game has ended in victory or defeat
+    pub fn is_over(&self) -> bool {
+        !matches!(self.game_state, GameState::Playing)
+    }
+
+    /// Grade of a solo victory, or `None` if the game isn't a won, unassisted solo game
+    pub fn victory_rank(&self) -> Option<VictoryRank> {
+        if !matches!(self.game_state, GameState::Victory) || self.assisted || !self.is_solo() {
+            return None;
+        }
+        match self.jesters_used {
+            0 => Some(VictoryRank::Gold),
+            1 => Some(VictoryRank::Silver),
+            _ => Some(VictoryRank::Bronze),
+        }
+    }
+
+    /// Check if the current player may yield. In multiplayer, someone has to
+    /// play once every other player has yielded in a row.
+    pub fn can_yield(&self) -> bool {
+        self.is_solo() || self.consecutive_yields < self.players.len() - 1
+    }
+
+    /// Apply a player action, advancing the turn through its phases
+    pub fn apply(&mut self, action: GameAction) -> Result<Vec<GameEvent>, RuleError> {
+        if self.is_over() {
+            return Err(RuleError::GameOver);
+        }
+
+        let first_event = self.events.len();
+        let recorded = action.clone();
+
+        match (action, self.phase) {
+            (GameAction::Play(card_indices), Phase::Play) => {
+                let enemy_defeated = self.play_cards(card_indices)?;
+
+                // Only enemy attacks if no enemy was defeated AND Jester was not played
+                // (If enemy was defeated, new enemy appears and the same player goes again)
+                // (If Jester was played, skip Step 4 per rules)
+                if self.jester_played_this_turn {
+                    if !self.is_solo() {
+                        self.phase = Phase::ChooseNextPlayer;
+                    }
+                } else if !enemy_defeated && !self.is_over() {
+                    self.resolve_enemy_attack()?;
+                }
+            }
+            (GameAction::Yield, Phase::Play) => {
+                self.yield_turn()?;
+                self.resolve_enemy_attack()?;
+            }
+            (GameAction::Discard(card_indices), Phase::Discard { .. }) => {
+                self.discard_to_survive(card_indices)?;
+                self.phase = Phase::Play;
+                self.emit(GameEvent::AttackSurvived);
+                self.end_turn();
+            }
+            (GameAction::ChooseNextPlayer(player), Phase::ChooseNextPlayer) => {
+                if player >= self.players.len() {
+                    return Err(RuleError::InvalidPlayer(player));
+                }
+                self.current_player = player;
+                self.phase = Phase::Play;
+                self.emit(GameEvent::NextPlayerChosen { player });
+            }
+            (GameAction::UseSoloJester, _) if !self.is_solo() => {
+                return Err(RuleError::SoloOnly);
+            }
+            (GameAction::UseSoloJester, _) => {
+                // A fresh hand at Step 4 still has to cover the attack, which
+                // the legal move check below sees to
+                self.use_jester()?;
+            }
+            (GameAction::Play(_) | GameAction::Yield, phase @ Phase::Discard { .. })
+            | (GameAction::Discard(_), phase @ Phase::Play)
+            | (GameAction::ChooseNextPlayer(_), phase)
+            | (
+                GameAction::Play(_) | GameAction::Yield | GameAction::Discard(_),
+                phase @ Phase::ChooseNextPlayer,
+            ) => {
+                return Err(RuleError::WrongPhase { phase });
+            }
+        }
+
+        if !self.is_over() {
+            if let Some(reason) = self.stuck_reason() {
+                self.defeat(reason);
+            }
+        }
+
+        self.actions.push(recorded);
+        Ok(self.events[first_event..].to_vec())
+    }
+
+    /// Apply an action on behalf of `seat`, rejecting it if it isn't their turn
+    pub fn apply_as(
+        &mut self,
+        seat: usize,
+        action: GameAction,
+    ) -> Result<Vec<GameEvent>, RuleError> {
+        if seat != self.current_player && !self.is_over() {
+            return Err(RuleError::NotYourTurn {
+                current: self.current_player,
+            });
+        }
+        self.apply(action)
+    }
+
+    /// Enemy attack (Step 4): move to the discard phase unless shields absorb it all
+    fn resolve_enemy_attack(&mut self) -> Result<(), RuleError> {
+        let damage = self.enemy_attack()?;
+
+        // If damage is 0, continue to next turn
+        if damage > 0 {
+            self.phase = Phase::Discard { required: damage };
+        } else {
+            self.end_turn();
+        }
+
+        Ok(())
+    }
+
+    /// Check if the current player has any legal action in the current phase
+    pub fn has_legal_move(&self) -> bool {
+        self.stuck_reason().is_none()
+    }
+
+    /// Why the current player has no legal action, if they have none
+    fn stuck_reason(&self) -> Option<String> {
+        // A solo Jester power only helps if there are cards to refill the hand with
+        let can_refill = self.can_use_solo_jester() && !self.tavern_deck.is_empty();
+
+        match self.phase {
+            Phase::Discard { required } => (!self.player().can_survive(required) && !can_refill)
+                .then(|| "Cannot survive enemy attack!".to_string()),
+            Phase::Play if !self.player().hand.is_empty() || can_refill => None,
+            Phase::Play if self.players.iter().all(|p| p.hand.is_empty()) => {
+                // Yielding around the table could never get a card played
+                Some(if self.is_solo() {
+                    "No cards left to play and no way to draw more".to_string()
+                } else {
+                    "Every hand is empty, so no one can play a card".to_string()
+                })
+            }
+            Phase::Play if !self.can_yield() => Some(format!(
+                "{} has no cards to play and cannot yield",
+                self.player().name
+            )),
+            Phase::Play | Phase::ChooseNextPlayer => None,
+        }
+    }
+
+    /// Look up the selected cards, rejecting empty, out-of-range or repeated indices
+    fn selected_cards(&self, card_indices: &[usize]) -> Result<Vec<&Card>, RuleError> {
         if card_indices.is_empty() {
-            return Err("Must select at least one card".to_string());
+            return Err(RuleError::EmptySelection);
         }
 
-        let cards: Vec<&Card> = card_indices
-            .iter()
-            .filter_map(|&i| self.player.hand.get(i))
-            .collect();
+        let mut cards = Vec::with_capacity(card_indices.len());
+        for (pos, &index) in card_indices.iter().enumerate() {
+            if card_indices[..pos].contains(&index) {
+                return Err(RuleError::InvalidIndex(index));
+            }
+            cards.push(
+                self.player()
+                    .hand
+                    .get(index)
+                    .ok_or(RuleError::InvalidIndex(index))?,
+            );
+        }
+
+        Ok(cards)
+    }
+
+    /// Every set of cards the current player may play, as sorted hand indices
+    pub fn legal_plays(&self) -> Vec<Vec<usize>> {
+        legal_plays_in(&self.player().hand)
+    }
+
+    /// Every action worth searching for the current player: each legal play,
+    /// each discard that covers the attack (with `minimal_discards`, only
+    /// those that need every card they use), and yields, solo Jesters and
+    /// next-player choices where allowed
+    pub fn candidate_actions(&self, minimal_discards: bool) -> Vec<GameAction> {
+        let mut actions = Vec::new();
+        match self.phase {
+            Phase::Play => {
+                actions.extend(self.legal_plays().into_iter().map(GameAction::Play));
+                // A solo yield behind a full shield changes nothing
+                let idle = self.is_solo()
+                    && self
+                        .current_enemy
+                        .as_ref()
+                        .is_some_and(|e| e.attack <= self.shield_value);
+                if self.can_yield() && !idle {
+                    actions.push(GameAction::Yield);
+                }
+            }
+            Phase::Discard { required } => {
+                let hand = &self.player().hand;
+                for mask in 1..1u32 << hand.len() {
+                    let cards: Vec<usize> =
+                        (0..hand.len()).filter(|i| mask & (1 << i) != 0).collect();
+                    let values = cards.iter().map(|&i| hand[i].value() as u32);
+                    let total: u32 = values.clone().sum();
+                    // Minimal: dropping any one card would no longer cover the attack
+                    let minimal = values
+                        .min()
+                        .is_some_and(|low| total - low < required as u32);
+                    if total >= required as u32 && (minimal || !minimal_discards) {
+                        actions.push(GameAction::Discard(cards));
+                    }
+                }
+            }
+            Phase::ChooseNextPlayer => {
+                actions.extend((0..self.players.len()).map(GameAction::ChooseNextPlayer));
+            }
+        }
+        if self.can_use_solo_jester() && self.phase != Phase::ChooseNextPlayer {
+            actions.push(GameAction::UseSoloJester);
+        }
+        actions
+    }
+
+    /// How far the shuffles have got through the seed's random stream. With
+    /// the seed, this pins down every shuffle still to come.
+    pub(crate) fn rng_position(&self) -> u128 {
+        self.rng.get_word_pos()
+    }
+
+    /// A copy of the game with everything `seat` can't see dealt afresh: the
+    /// tavern deck and other players' hands are shuffled together and redealt,
+    /// the castle is shuffled within each layer, and future shuffles get a new
+    /// seed. The discard pile, the cards in play, the seat's own hand and any
+    /// captured enemies face up on the tavern stay as they are. The copy has
+    /// no log or history, so searches can clone it cheaply.
+    pub fn determinized_for<R: rand::Rng + ?Sized>(&self, seat: usize, rng: &mut R) -> Game {
+        let mut game = Game {
+            game_log: Vec::new(),
+            events: Vec::new(),
+            actions: Vec::new(),
+            rng: GameRng::seed_from_u64(rng.gen()),
+            ..self.clone()
+        };
+
+        let face_up = game.tavern_deck.face_up_cards().len();
+        let mut tavern = std::mem::take(&mut game.tavern_deck.cards);
+        let known = tavern.split_off(tavern.len() - face_up);
+        let mut unseen = tavern;
+        for (i, player) in game.players.iter_mut().enumerate() {
+            if i != seat {
+                unseen.append(&mut player.hand);
+            }
+        }
+        unseen.shuffle(rng);
+        for (i, player) in game.players.iter_mut().enumerate() {
+            if i != seat {
+                let size = self.players[i].hand.len();
+                player.hand = unseen.split_off(unseen.len() - size);
+            }
+        }
+        unseen.extend(known);
+        game.tavern_deck.cards = unseen;
 
-        if cards.len() != card_indices.len() {
-            return Err("Invalid card indices".to_string());
+        // Kings, Queens and Jacks each keep their place in the castle
+        for layer in game.castle_deck.cards.chunk_by_mut(|a, b| a.rank == b.rank) {
+            layer.shuffle(rng);
         }
+        game
+    }
+
+    /// Validate if cards can be played together
+    pub fn validate_play(&self, card_indices: &[usize]) -> Result<(), RuleError> {
+        let cards = self.selected_cards(card_indices)?;
 
         // Jester must be played alone
         if cards.iter().any(|c| c.is_jester()) {
             if cards.len() > 1 {
-                return Err("Jester must be played alone".to_string());
+                return Err(RuleError::JesterNotAlone);
             }
             return Ok(());
         }
@@ -127,7 +569,7 @@ impl Game {
             } else if cards.len() == 2 && ace_count == 2 {
                 return Ok(()); // Ace + Ace is valid
             } else if cards.len() > 2 {
-                return Err("Ace can only be paired with one other card".to_string());
+                return Err(RuleError::AceWithTooMany);
             }
             // If we get here, we have aces but not in valid combo - fall through to same-rank check
         }
@@ -135,19 +577,17 @@ impl Game {
         // Combo: 2-4 cards of same rank, total <= 10
         // First, ensure we don't have more than 4 cards
         if cards.len() > 4 {
-            return Err("Cannot play more than 4 cards at once".to_string());
+            return Err(RuleError::TooManyCards);
         }
 
         let first_rank = cards[0].rank;
         if !cards.iter().all(|c| c.rank == first_rank) {
-            return Err(
-                "Combo cards must all have the same rank (or use Ace + 1 card)".to_string(),
-            );
+            return Err(RuleError::MixedRankCombo);
         }
 
         let total: u8 = cards.iter().map(|c| c.value()).sum();
         if total > 10 {
-            return Err("Combo total must be 10 or less".to_string());
+            return Err(RuleError::ComboOverTen { total });
         }
 
         Ok(())
@@ -155,25 +595,22 @@ impl Game {
 
     /// Play cards from hand (Step 1 & 2)
     /// Returns true if enemy was defeated (and a new one appeared)
-    pub fn play_cards(&mut self, card_indices: Vec<usize>) -> Result<bool, String> {
+    fn play_cards(&mut self, card_indices: Vec<usize>) -> Result<bool, RuleError> {
         // Reset Jester flag at the start of a new turn
         self.jester_played_this_turn = false;
 
         // Validate the play
         self.validate_play(&card_indices)?;
+        self.consecutive_yields = 0;
 
         // Remove cards from hand
-        let cards = self.player.play_cards(card_indices);
-
-        if cards.is_empty() {
-            return Err("Failed to play cards".to_string());
-        }
+        let cards = self.player_mut().play_cards(card_indices);
 
         let attack_value: u8 = cards.iter().map(|c| c.value()).sum();
 
         // Handle Jester special case
         if cards[0].is_jester() {
-            self.log("Played Jester - Enemy immunity cancelled!");
+            self.emit(GameEvent::JesterPlayed);
             if let Some(ref mut enemy) = self.current_enemy {
                 let enemy_suit = enemy.card.suit;
                 enemy.cancel_immunity();
@@ -191,10 +628,11 @@ impl Game {
 
                     if retroactive_shield > 0 {
                         self.shield_value += retroactive_shield;
-                        self.log(format!(
-                            "Spades now active! Shield increased by {} (Total: {})",
-                            retroactive_shield, self.shield_value
-                        ));
+                        self.emit(GameEvent::ShieldRaised {
+                            amount: retroactive_shield,
+                            total: self.shield_value,
+                            retroactive: true,
+                        });
                     }
                 }
             }
@@ -204,16 +642,10 @@ impl Game {
             return Ok(false);
         }
 
-        // Store current enemy for comparison
-        let enemy_before = self.current_enemy.as_ref().map(|e| e.card);
-
-        // Log the play
-        let card_names: Vec<String> = cards.iter().map(|c| c.display()).collect();
-        self.log(format!(
-            "Played: {} (Attack: {})",
-            card_names.join(", "),
-            attack_value
-        ));
+        self.emit(GameEvent::CardsPlayed {
+            cards: cards.clone(),
+            attack: attack_value,
+        });
 
         // Apply suit powers (Step 2)
         self.apply_suit_powers(&cards, attack_value)?;
@@ -223,24 +655,19 @@ impl Game {
         self.played_cards.extend(cards.clone());
 
         // Deal damage (Step 3) - pass cards to check for Clubs in THIS turn only
-        self.deal_damage(attack_value, &cards)?;
-
-        // Check if enemy was defeated (new enemy appeared)
-        let enemy_defeated = enemy_before != self.current_enemy.as_ref().map(|e| e.card);
-
-        Ok(enemy_defeated)
+        self.deal_damage(attack_value, &cards)
     }
 
     /// Apply suit powers to the cards played
-    fn apply_suit_powers(&mut self, cards: &[Card], attack_value: u8) -> Result<(), String> {
-        let enemy = self.current_enemy.as_ref().ok_or("No current enemy")?;
+    fn apply_suit_powers(&mut self, cards: &[Card], attack_value: u8) -> Result<(), RuleError> {
+        let enemy = self.current_enemy.as_ref().ok_or(RuleError::NoEnemy)?;
 
         // Collect suits and check immunity
         let mut hearts_power = 0;
         let mut diamonds_power = 0;
         let mut clubs_active = false;
         let mut spades_power = 0;
-        let mut log_messages = Vec::new();
+        let mut blocked = Vec::new();
 
         for card in cards {
             match card.suit {
@@ -248,38 +675,36 @@ impl Game {
                     if !enemy.is_immune_to(Suit::Hearts) {
                         hearts_power = attack_value;
                     } else {
-                        log_messages.push("Hearts power blocked by immunity".to_string());
+                        blocked.push(Suit::Hearts);
                     }
                 }
                 Suit::Diamonds => {
                     if !enemy.is_immune_to(Suit::Diamonds) {
                         diamonds_power = attack_value;
                     } else {
-                        log_messages.push("Diamonds power blocked by immunity".to_string());
+                        blocked.push(Suit::Diamonds);
                     }
                 }
                 Suit::Clubs => {
                     if !enemy.is_immune_to(Suit::Clubs) {
                         clubs_active = true;
                     } else {
-                        log_messages.push(
-                            "Clubs power blocked by immunity (double damage negated)".to_string(),
-                        );
+                        blocked.push(Suit::Clubs);
                     }
                 }
                 Suit::Spades => {
                     if !enemy.is_immune_to(Suit::Spades) {
                         spades_power = attack_value;
                     } else {
-                        log_messages.push("Spades power blocked by immunity".to_string());
+                        blocked.push(Suit::Spades);
                     }
                 }
             }
         }
 
-        // Log immunity messages
-        for msg in log_messages {
-            self.log(msg);
+        // Report immunity blocks
+        for suit in blocked {
+            self.emit(GameEvent::PowerBlocked { suit });
         }
 
         // Apply Hearts first (heal discard pile into tavern deck)
@@ -287,61 +712,62 @@ impl Game {
             let heal_count = hearts_power.min(self.discard_pile.len() as u8) as usize;
             if heal_count > 0 {
                 // Shuffle discard pile
-                let mut temp_deck = Deck::new();
-                temp_deck.cards = self.discard_pile.clone();
-                temp_deck.shuffle();
+                self.discard_pile.shuffle(&mut self.rng);
 
                 // Take cards from shuffled discard
-                let healed: Vec<Card> = temp_deck.cards.drain(..heal_count).collect();
-                self.discard_pile = temp_deck.cards;
+                let healed: Vec<Card> = self.discard_pile.drain(..heal_count).collect();
 
                 // Add to bottom of tavern deck
                 self.tavern_deck.add_multiple_to_bottom(healed);
-                self.log(format!(
-                    "Healed {} cards from discard to tavern deck",
-                    heal_count
-                ));
+                self.emit(GameEvent::Healed { count: heal_count });
             }
         }
 
-        // Apply Diamonds (draw cards)
+        // Apply Diamonds (draw cards one at a time, starting with the current player
+        // and going around the table, skipping full hands)
         if diamonds_power > 0 {
             let mut cards_to_draw = diamonds_power as usize;
             let mut drawn = 0;
-            while cards_to_draw > 0 && !self.player.is_hand_full() {
-                if let Some(card) = self.tavern_deck.draw() {
-                    self.player.draw_card(card);
-                    drawn += 1;
-                    cards_to_draw -= 1;
-                } else {
-                    break;
+            let mut seat = self.current_player;
+            while cards_to_draw > 0 && self.players.iter().any(|p| !p.is_hand_full()) {
+                if !self.players[seat].is_hand_full() {
+                    if let Some(card) = self.tavern_deck.draw() {
+                        self.players[seat].draw_card(card);
+                        drawn += 1;
+                        cards_to_draw -= 1;
+                    } else {
+                        break;
+                    }
                 }
+                seat = (seat + 1) % self.players.len();
             }
             if drawn > 0 {
-                self.log(format!("Drew {} cards", drawn));
+                self.emit(GameEvent::Drew { count: drawn });
             }
         }
 
         // Store clubs status for damage calculation
         if clubs_active {
-            self.log("Clubs active - double damage!");
+            self.emit(GameEvent::DamageDoubled);
         }
 
         // Apply Spades (shield - cumulative)
         if spades_power > 0 {
             self.shield_value += spades_power;
-            self.log(format!(
-                "Shield increased by {} (Total: {})",
-                spades_power, self.shield_value
-            ));
+            self.emit(GameEvent::ShieldRaised {
+                amount: spades_power,
+                total: self.shield_value,
+                retroactive: false,
+            });
         }
 
         Ok(())
     }
 
     /// Deal damage to the enemy (Step 3)
-    fn deal_damage(&mut self, mut attack_value: u8, cards: &[Card]) -> Result<(), String> {
-        let enemy = self.current_enemy.as_mut().ok_or("No current enemy")?;
+    /// Returns true if the enemy was defeated (and a new one appeared)
+    fn deal_damage(&mut self, mut attack_value: u8, cards: &[Card]) -> Result<bool, RuleError> {
+        let enemy = self.current_enemy.as_mut().ok_or(RuleError::NoEnemy)?;
 
         // Check if clubs were played in THIS turn only (not previous turns)
         let clubs_played = cards
@@ -359,16 +785,17 @@ impl Game {
         // Check if enemy is defeated
         let is_defeated = enemy.is_defeated();
 
-        self.log(format!(
-            "Dealt {} damage (Total: {}/{})",
-            attack_value, self.total_damage, max_hp
-        ));
+        self.emit(GameEvent::DamageDealt {
+            damage: attack_value,
+            total: self.total_damage,
+            max_hp,
+        });
 
         if is_defeated {
             self.enemy_defeated();
         }
 
-        Ok(())
+        Ok(is_defeated)
     }
 
     /// Handle enemy defeat
@@ -377,10 +804,10 @@ impl Game {
 
         // Check if defeated with exact damage
         if enemy.defeated_exactly(self.total_damage) {
-            self.log(format!("Exact damage! {} captured!", enemy.name()));
+            self.emit(GameEvent::EnemyCaptured { enemy: enemy.card });
             self.tavern_deck.add_to_top(enemy.card);
         } else {
-            self.log(format!("{} defeated!", enemy.name()));
+            self.emit(GameEvent::EnemyDefeated { enemy: enemy.card });
             self.discard_pile.push(enemy.card);
         }
 
@@ -392,84 +819,93 @@ impl Game {
     }
 
     /// Yield turn (skip to enemy attack)
-    pub fn yield_turn(&mut self) -> Result<(), String> {
+    fn yield_turn(&mut self) -> Result<(), RuleError> {
+        if !self.can_yield() {
+            return Err(RuleError::EveryoneElseYielded);
+        }
+        self.consecutive_yields += 1;
+
         // Reset Jester flag at the start of a new turn
         self.jester_played_this_turn = false;
-        self.log("Yielded turn");
+        self.emit(GameEvent::Yielded);
         Ok(())
     }
 
     /// Enemy attacks (Step 4)
-    pub fn enemy_attack(&mut self) -> Result<u8, String> {
-        let enemy = self.current_enemy.as_ref().ok_or("No current enemy")?;
+    fn enemy_attack(&mut self) -> Result<u8, RuleError> {
+        let enemy = self.current_enemy.as_ref().ok_or(RuleError::NoEnemy)?;
 
         let damage = enemy.get_attack_after_shields(self.shield_value);
-
-        if damage > 0 {
-            self.log(format!("Enemy attacks for {} damage!", damage));
-        } else {
-            self.log("Enemy attack fully blocked by shields!");
-        }
+        self.emit(GameEvent::EnemyAttack { damage });
 
         Ok(damage)
     }
 
     /// Discard cards to survive enemy attack
-    pub fn discard_to_survive(&mut self, card_indices: Vec<usize>) -> Result<(), String> {
-        let value = self.player.calculate_value(&card_indices);
-        let enemy = self.current_enemy.as_ref().ok_or("No current enemy")?;
+    fn discard_to_survive(&mut self, card_indices: Vec<usize>) -> Result<(), RuleError> {
+        self.selected_cards(&card_indices)?;
+        let value = self.player().calculate_value(&card_indices);
+        let enemy = self.current_enemy.as_ref().ok_or(RuleError::NoEnemy)?;
         let required = enemy.get_attack_after_shields(self.shield_value);
 
         if value < required {
-            return Err(format!(
-                "Not enough value (need {}, have {})",
-                required, value
-            ));
+            return Err(RuleError::NotEnoughDiscardValue {
+                need: required,
+                have: value,
+            });
         }
 
         // Discard the cards
-        let discarded = self.player.play_cards(card_indices);
-        let card_names: Vec<String> = discarded.iter().map(|c| c.display()).collect();
-        self.log(format!(
-            "Discarded: {} (Value: {})",
-            card_names.join(", "),
-            value
-        ));
-        self.discard_pile.extend(discarded);
+        let discarded = self.player_mut().play_cards(card_indices);
+        self.discard_pile.extend(discarded.iter().copied());
+        self.emit(GameEvent::Discarded {
+            cards: discarded,
+            value,
+        });
 
         Ok(())
     }
 
     /// Use a Jester (solo mode only)
-    pub fn use_jester(&mut self) -> Result<(), String> {
+    fn use_jester(&mut self) -> Result<(), RuleError> {
         if self.jesters_used >= self.jester_count {
-            return Err("No Jesters remaining".to_string());
+            return Err(RuleError::NoJestersLeft);
         }
 
         // Discard hand
-        let hand_size = self.player.hand.len();
-        let discarded: Vec<Card> = self.player.hand.drain(..).collect();
+        let hand_size = self.player().hand.len();
+        let discarded: Vec<Card> = self.player_mut().hand.drain(..).collect();
         self.discard_pile.extend(discarded);
 
-        // Refill to 8 cards
-        let cards = self.tavern_deck.draw_multiple(8);
-        self.player.draw_multiple(cards);
+        // Refill to a full hand
+        let cards = self.tavern_deck.draw_multiple(self.player().max_hand_size);
+        self.player_mut().draw_multiple(cards);
 
         self.jesters_used += 1;
-        self.log(format!(
-            "Used Jester power! Discarded {} cards and drew fresh hand ({} Jesters remaining)",
-            hand_size,
-            self.jester_count - self.jesters_used
-        ));
+        self.emit(GameEvent::JesterUsed {
+            discarded: hand_size,
+            remaining: self.jester_count - self.jesters_used,
+        });
 
         Ok(())
     }
 }
 
+/// A solo game against `enemy` with `hand`, shared by the tests of modules
+/// that search for moves
+#[cfg(test)]
+pub(crate) fn solo_against(enemy: Card, hand: Vec<Card>) -> Game {
+    let mut game = Game::new_solo_with_seed(20);
+    game.current_enemy = Some(Enemy::new(enemy));
+    game.players[0].hand = hand;
+    game
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::card::{Card, Rank, Suit};
+    use rand::Rng;
 
     #[test]
     fn test_jester_skips_step_4() {
@@ -478,24 +914,21 @@ mod tests {
 
         // Add a Jester to the player's hand
         let jester = Card::new(Suit::Hearts, Rank::Jester);
-        game.player.hand.clear();
-        game.player.hand.push(jester);
+        game.players[0].hand.clear();
+        game.players[0].hand.push(jester);
 
         // Play the Jester
         let result = game.play_cards(vec![0]);
 
         // Should succeed and not defeat enemy
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), false); // enemy not defeated
+        assert!(!result.unwrap()); // enemy not defeated
 
         // Jester flag should be set to skip Step 4
-        assert_eq!(game.jester_played_this_turn, true);
+        assert!(game.jester_played_this_turn);
 
         // Immunity should be cancelled
-        assert_eq!(
-            game.current_enemy.as_ref().unwrap().immunity_cancelled,
-            true
-        );
+        assert!(game.current_enemy.as_ref().unwrap().immunity_cancelled);
     }
 
     #[test]
@@ -508,8 +941,8 @@ mod tests {
 
         // Add a card to hand
         let card = Card::new(Suit::Hearts, Rank::Five);
-        game.player.hand.clear();
-        game.player.hand.push(card);
+        game.players[0].hand.clear();
+        game.players[0].hand.push(card);
 
         // Play a normal card
         let result = game.play_cards(vec![0]);
@@ -518,7 +951,7 @@ mod tests {
         assert!(result.is_ok());
 
         // Jester flag should be reset to false
-        assert_eq!(game.jester_played_this_turn, false);
+        assert!(!game.jester_played_this_turn);
     }
 
     #[test]
@@ -536,7 +969,7 @@ mod tests {
         assert!(result.is_ok());
 
         // Jester flag should be reset to false
-        assert_eq!(game.jester_played_this_turn, false);
+        assert!(!game.jester_played_this_turn);
     }
 
     #[test]
@@ -545,9 +978,13 @@ mod tests {
         let mut game = Game::new_solo();
 
         // Setup: Give player only low value cards
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Two));
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Two));
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Two));
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Two));
 
         let jesters_before = game.jesters_used;
 
@@ -558,7 +995,11 @@ mod tests {
         assert!(result.is_ok());
 
         // Hand should be refilled to 8 cards
-        assert_eq!(game.player.hand.len(), 8, "Hand should be refilled to 8");
+        assert_eq!(
+            game.players[0].hand.len(),
+            8,
+            "Hand should be refilled to 8"
+        );
 
         // Jester count should increment
         assert_eq!(
@@ -583,7 +1024,7 @@ mod tests {
         // Third attempt should fail
         let result = game.use_jester();
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "No Jesters remaining");
+        assert_eq!(result.unwrap_err(), RuleError::NoJestersLeft);
     }
 
     #[test]
@@ -595,10 +1036,16 @@ mod tests {
         game.current_enemy = Some(Enemy::new(Card::new(Suit::Spades, Rank::Jack)));
 
         // Setup player hand: 5♠, Jester, 5♥
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Spades, Rank::Five));
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Jester));
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Five));
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Spades, Rank::Five));
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Jester));
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Five));
 
         // Turn 1: Play 5 of Spades against Jack of Spades
         // Should be blocked by immunity, shield stays 0
@@ -613,9 +1060,8 @@ mod tests {
         // Turn 2: Play Jester to cancel immunity
         let result = game.play_cards(vec![0]);
         assert!(result.is_ok());
-        assert_eq!(
+        assert!(
             game.current_enemy.as_ref().unwrap().immunity_cancelled,
-            true,
             "Immunity should be cancelled"
         );
         // Shield should now include the retroactive Spades (value 5)
@@ -647,9 +1093,13 @@ mod tests {
         game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack)));
 
         // Setup: Give player 5 of Clubs and 5 of Hearts
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Clubs, Rank::Five));
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Five));
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Clubs, Rank::Five));
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Five));
 
         // Turn 1: Play 5 of Clubs -> should deal 10 damage (5 doubled)
         let result = game.play_cards(vec![0]);
@@ -674,10 +1124,14 @@ mod tests {
         game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack)));
 
         // Setup: Give player 3♣, 3♥, 3♦ for a combo (total 9 <= 10)
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Clubs, Rank::Three));
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Three));
-        game.player
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Clubs, Rank::Three));
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Three));
+        game.players[0]
             .hand
             .push(Card::new(Suit::Diamonds, Rank::Three));
 
@@ -690,6 +1144,619 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_victory_rank() {
+        let mut game = Game::new_solo_with_seed(3);
+        assert_eq!(game.victory_rank(), None); // Still playing
+
+        game.game_state = GameState::Victory;
+        assert_eq!(game.victory_rank(), Some(VictoryRank::Gold));
+        game.jesters_used = 1;
+        assert_eq!(game.victory_rank(), Some(VictoryRank::Silver));
+        game.jesters_used = 2;
+        assert_eq!(game.victory_rank(), Some(VictoryRank::Bronze));
+
+        // Undone games don't count toward a rank
+        game.assisted = true;
+        assert_eq!(game.victory_rank(), None);
+    }
+
+    #[test]
+    fn test_same_seed_deals_same_game() {
+        // Test that a seed reproduces the castle and tavern order card-for-card
+        let a = Game::new_solo_with_seed(42);
+        let b = Game::new_solo_with_seed(42);
+
+        assert_eq!(a.seed, 42);
+        assert_eq!(a.castle_deck.cards, b.castle_deck.cards);
+        assert_eq!(a.tavern_deck.cards, b.tavern_deck.cards);
+        assert_eq!(a.players[0].hand, b.players[0].hand);
+        assert_eq!(
+            a.current_enemy.as_ref().unwrap().card,
+            b.current_enemy.as_ref().unwrap().card
+        );
+
+        let c = Game::new_solo_with_seed(43);
+        assert_ne!(a.tavern_deck.cards, c.tavern_deck.cards);
+    }
+
+    #[test]
+    fn test_hearts_shuffle_uses_game_rng() {
+        // Test that healing from the discard pile is reproducible for a given seed
+        let mut games = [Game::new_solo_with_seed(7), Game::new_solo_with_seed(7)];
+
+        for game in games.iter_mut() {
+            game.current_enemy = Some(Enemy::new(Card::new(Suit::Spades, Rank::Jack)));
+            for rank in [Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six] {
+                game.discard_pile.push(Card::new(Suit::Clubs, rank));
+            }
+            game.players[0].hand.clear();
+            game.players[0]
+                .hand
+                .push(Card::new(Suit::Hearts, Rank::Three));
+            assert!(game.play_cards(vec![0]).is_ok());
+        }
+
+        assert_eq!(games[0].discard_pile, games[1].discard_pile);
+        assert_eq!(games[0].tavern_deck.cards, games[1].tavern_deck.cards);
+    }
+
+    #[test]
+    fn test_apply_yield_enters_discard_phase() {
+        // Test that yielding moves the turn to Step 4 and discarding returns to Step 1
+        let mut game = Game::new_solo_with_seed(1);
+        game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack))); // Attack 10
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Clubs, Rank::Five));
+        game.players[0].hand.push(Card::new(Suit::Clubs, Rank::Six));
+
+        let events = game.apply(GameAction::Yield).unwrap();
+        assert_eq!(
+            events,
+            vec![GameEvent::Yielded, GameEvent::EnemyAttack { damage: 10 }]
+        );
+        assert_eq!(game.phase, Phase::Discard { required: 10 });
+
+        // Playing is not allowed until the attack has been absorbed
+        assert!(game.apply(GameAction::Play(vec![0])).is_err());
+        assert!(game.apply(GameAction::Yield).is_err());
+
+        let events = game.apply(GameAction::Discard(vec![0, 1])).unwrap();
+        assert_eq!(events.last(), Some(&GameEvent::AttackSurvived));
+        assert_eq!(game.phase, Phase::Play);
+        assert!(game.players[0].hand.is_empty());
+    }
+
+    #[test]
+    fn test_apply_discard_outside_attack_fails() {
+        let mut game = Game::new_solo_with_seed(1);
+        assert!(game.apply(GameAction::Discard(vec![0])).is_err());
+        assert!(game.apply(GameAction::ChooseNextPlayer(0)).is_err());
+        assert_eq!(game.phase, Phase::Play);
+    }
+
+    #[test]
+    fn test_apply_unsurvivable_attack_is_defeat() {
+        // Test that an attack the hand cannot cover ends the game once solo Jesters are spent
+        let mut game = Game::new_solo_with_seed(1);
+        game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack)));
+        game.jesters_used = game.jester_count;
+        game.players[0].hand.clear();
+        game.players[0].hand.push(Card::new(Suit::Clubs, Rank::Two));
+
+        let events = game.apply(GameAction::Yield).unwrap();
+        assert!(matches!(events.last(), Some(GameEvent::Defeat { .. })));
+        assert!(matches!(game.game_state, GameState::Defeat(_)));
+        assert!(game.apply(GameAction::Yield).is_err(), "Game is over");
+    }
+
+    #[test]
+    fn test_legal_plays_covers_every_kind_of_play() {
+        let mut game = Game::new_solo_with_seed(5);
+        game.players[0].hand = vec![
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Spades, Rank::Five),
+            Card::new(Suit::Clubs, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Jester),
+            Card::new(Suit::Diamonds, Rank::Six),
+        ];
+
+        assert_eq!(
+            game.legal_plays(),
+            vec![
+                vec![0],
+                vec![1],
+                vec![2],
+                vec![3],
+                vec![4],
+                vec![0, 1], // 5 + 5
+                vec![0, 2], // Ace pairings
+                vec![1, 2],
+                vec![2, 4],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_play_agrees_with_legal_plays() {
+        // Random hands from every card that can reach a hand, captured royals included
+        let mut pool = Deck::create_tavern_deck(2, &mut GameRng::seed_from_u64(0)).cards;
+        pool.extend(Deck::create_castle_deck(&mut GameRng::seed_from_u64(0)).cards);
+        let mut rng = GameRng::seed_from_u64(18);
+        let mut game = Game::new_solo_with_seed(18);
+
+        for _ in 0..300 {
+            let size = rng.gen_range(0..=8);
+            game.players[0].hand = pool.choose_multiple(&mut rng, size).copied().collect();
+            let legal = game.legal_plays();
+
+            for mask in 1..1u32 << size {
+                let indices: Vec<usize> = (0..size).filter(|i| mask & (1 << i) != 0).collect();
+                assert_eq!(
+                    game.validate_play(&indices).is_ok(),
+                    legal.contains(&indices),
+                    "{:?} from {:?}",
+                    indices,
+                    game.players[0].hand
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_determinized_game_only_redeals_hidden_cards() {
+        let mut game = Game::new_with_seed(3, 9);
+        game.apply(GameAction::Play(vec![0])).unwrap();
+        let copy = game.determinized_for(1, &mut GameRng::seed_from_u64(1));
+
+        let sorted = |cards: Vec<Card>| {
+            let mut names: Vec<String> = cards.iter().map(Card::display).collect();
+            names.sort();
+            names
+        };
+        let hidden = |game: &Game| {
+            let mut cards = game.tavern_deck.cards.clone();
+            cards.extend(&game.players[0].hand);
+            cards.extend(&game.players[2].hand);
+            sorted(cards)
+        };
+
+        assert_eq!(copy.players[1].hand, game.players[1].hand);
+        assert_eq!(copy.played_cards, game.played_cards);
+        assert_eq!(copy.discard_pile, game.discard_pile);
+        assert_eq!(hidden(&copy), hidden(&game));
+        assert_ne!(copy.tavern_deck.cards, game.tavern_deck.cards);
+        for (i, player) in copy.players.iter().enumerate() {
+            assert_eq!(player.hand.len(), game.players[i].hand.len());
+        }
+        let ranks = |game: &Game| {
+            game.castle_deck
+                .cards
+                .iter()
+                .map(|c| c.rank)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ranks(&copy), ranks(&game));
+        assert!(copy.game_log.is_empty() && copy.actions.is_empty());
+
+        // A captured enemy lies face up on the tavern, so it stays the next draw
+        let jack = Card::new(Suit::Clubs, Rank::Jack);
+        game.tavern_deck.add_to_top(jack);
+        for seed in 0..5 {
+            let copy = game.determinized_for(1, &mut GameRng::seed_from_u64(seed));
+            assert_eq!(copy.tavern_deck.face_up_cards(), &[jack]);
+            assert_eq!(hidden(&copy), hidden(&game));
+        }
+        let mut copy = game.determinized_for(1, &mut GameRng::seed_from_u64(1));
+        assert_eq!(copy.tavern_deck.draw(), Some(jack));
+        assert!(copy.tavern_deck.face_up_cards().is_empty());
+    }
+
+    #[test]
+    fn test_candidate_discards_cover_the_attack() {
+        let mut game = Game::new_solo_with_seed(4);
+        game.players[0].hand = vec![
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Clubs, Rank::Three),
+            Card::new(Suit::Spades, Rank::Four),
+        ];
+        game.jesters_used = 2;
+        game.phase = Phase::Discard { required: 7 };
+
+        assert_eq!(
+            game.candidate_actions(true),
+            vec![
+                GameAction::Discard(vec![0, 1]),
+                GameAction::Discard(vec![0, 2]),
+                GameAction::Discard(vec![1, 2]),
+            ]
+        );
+        // Without the minimal filter, a discard with a card to spare is offered too
+        assert_eq!(
+            game.candidate_actions(false),
+            vec![
+                GameAction::Discard(vec![0, 1]),
+                GameAction::Discard(vec![0, 2]),
+                GameAction::Discard(vec![1, 2]),
+                GameAction::Discard(vec![0, 1, 2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solo_empty_hand_is_defeat() {
+        // Test that playing the last card with no Jester left ends the game
+        let mut game = Game::new_solo_with_seed(2);
+        game.jesters_used = game.jester_count;
+        game.shield_value = 20;
+        game.players[0].hand = vec![Card::new(Suit::Clubs, Rank::Two)];
+
+        let events = game.apply(GameAction::Play(vec![0])).unwrap();
+        assert!(!game.has_legal_move());
+        assert!(matches!(events.last(), Some(GameEvent::Defeat { .. })));
+        assert!(matches!(
+            &game.game_state,
+            GameState::Defeat(reason) if reason == "No cards left to play and no way to draw more"
+        ));
+    }
+
+    #[test]
+    fn test_solo_empty_hand_with_jester_needs_tavern() {
+        // A Jester power can refill an empty hand, but not from an empty tavern
+        let mut game = Game::new_solo_with_seed(3);
+        game.shield_value = 20;
+        game.players[0].hand = vec![Card::new(Suit::Clubs, Rank::Two)];
+        game.apply(GameAction::Play(vec![0])).unwrap();
+        assert!(game.has_legal_move());
+        assert!(matches!(game.game_state, GameState::Playing));
+
+        let mut game = Game::new_solo_with_seed(3);
+        game.shield_value = 20;
+        game.tavern_deck.cards.clear();
+        game.players[0].hand = vec![Card::new(Suit::Clubs, Rank::Two)];
+        game.apply(GameAction::Play(vec![0])).unwrap();
+        assert!(!game.has_legal_move());
+        assert!(matches!(game.game_state, GameState::Defeat(_)));
+    }
+
+    #[test]
+    fn test_solo_jester_into_empty_tavern_cannot_rescue_step_4() {
+        // With nothing to draw, a spent hand at Step 4 is a defeat even with Jesters left
+        let mut game = Game::new_solo_with_seed(4);
+        game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack)));
+        game.tavern_deck.cards.clear();
+        game.players[0].hand = vec![Card::new(Suit::Clubs, Rank::Two)];
+
+        game.apply(GameAction::Yield).unwrap();
+        assert!(matches!(
+            &game.game_state,
+            GameState::Defeat(reason) if reason == "Cannot survive enemy attack!"
+        ));
+    }
+
+    #[test]
+    fn test_multiplayer_empty_hand_that_cannot_yield_is_defeat() {
+        let mut game = Game::new_with_seed(2, 23);
+        game.shield_value = 20;
+        game.players[1].hand.clear();
+
+        // Player 2 could yield with an empty hand, but not after Player 1 yielded
+        let events = game.apply(GameAction::Yield).unwrap();
+        assert_eq!(game.current_player, 1);
+        assert!(!game.has_legal_move());
+        assert!(matches!(events.last(), Some(GameEvent::Defeat { .. })));
+        assert!(matches!(
+            &game.game_state,
+            GameState::Defeat(reason) if reason == "Player 2 has no cards to play and cannot yield"
+        ));
+    }
+
+    #[test]
+    fn test_multiplayer_empty_hand_can_yield_to_others() {
+        let mut game = Game::new_with_seed(3, 24);
+        game.shield_value = 20;
+        game.players[1].hand.clear();
+
+        let card = game.player().hand.iter().position(|c| !c.is_jester());
+        game.apply(GameAction::Play(vec![card.unwrap()])).unwrap();
+        assert_eq!(game.current_player, 1);
+        assert!(game.has_legal_move());
+        game.apply(GameAction::Yield).unwrap();
+        assert!(matches!(game.game_state, GameState::Playing));
+    }
+
+    #[test]
+    fn test_multiplayer_every_hand_empty_is_defeat() {
+        let mut game = Game::new_with_seed(3, 25);
+        game.shield_value = 20;
+        game.players[0].hand = vec![Card::new(Suit::Clubs, Rank::Two)];
+        game.players[1].hand.clear();
+        game.players[2].hand.clear();
+
+        game.apply(GameAction::Play(vec![0])).unwrap();
+        assert!(matches!(
+            &game.game_state,
+            GameState::Defeat(reason) if reason == "Every hand is empty, so no one can play a card"
+        ));
+    }
+
+    #[test]
+    fn test_apply_solo_jester_can_rescue_step_4() {
+        // Test that a weak hand is not an instant defeat while a solo Jester remains
+        let mut game = Game::new_solo_with_seed(1);
+        game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack)));
+        game.players[0].hand.clear();
+        game.players[0].hand.push(Card::new(Suit::Clubs, Rank::Two));
+
+        game.apply(GameAction::Yield).unwrap();
+        assert!(matches!(game.game_state, GameState::Playing));
+        assert_eq!(game.phase, Phase::Discard { required: 10 });
+
+        game.apply(GameAction::UseSoloJester).unwrap();
+        assert_eq!(game.players[0].hand.len(), 8);
+        assert_eq!(game.phase, Phase::Discard { required: 10 });
+    }
+
+    #[test]
+    fn test_apply_jester_skips_enemy_attack() {
+        let mut game = Game::new_solo_with_seed(1);
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Jester));
+
+        let events = game.apply(GameAction::Play(vec![0])).unwrap();
+        assert_eq!(events, vec![GameEvent::JesterPlayed]);
+        assert_eq!(game.phase, Phase::Play);
+    }
+
+    #[test]
+    fn test_new_game_by_player_count() {
+        // (players, hand size, Jesters in the tavern)
+        for (count, hand_size, jesters) in [(1, 8, 0), (2, 7, 0), (3, 6, 1), (4, 5, 2)] {
+            let game = Game::new_with_seed(count, 5);
+            assert_eq!(game.players.len(), count);
+            assert_eq!(game.current_player, 0);
+            for player in &game.players {
+                assert_eq!(player.max_hand_size, hand_size);
+                assert_eq!(player.hand.len(), hand_size);
+            }
+
+            let all_cards: Vec<&Card> = game
+                .players
+                .iter()
+                .flat_map(|p| &p.hand)
+                .chain(&game.tavern_deck.cards)
+                .collect();
+            assert_eq!(all_cards.len(), 40 + jesters);
+            assert_eq!(all_cards.iter().filter(|c| c.is_jester()).count(), jesters);
+
+            // Solo Jester powers replace the tavern Jesters
+            assert_eq!(game.jester_count, if count == 1 { 2 } else { 0 });
+        }
+    }
+
+    #[test]
+    fn test_turn_passes_after_surviving_attack() {
+        let mut game = Game::new_with_seed(2, 11);
+        game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack))); // Attack 10
+        game.players[0].hand = vec![
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Spades, Rank::Ten),
+        ];
+
+        game.apply(GameAction::Play(vec![0])).unwrap();
+        assert_eq!(game.phase, Phase::Discard { required: 10 });
+        assert_eq!(game.current_player, 0); // The attacked player discards
+
+        let events = game.apply(GameAction::Discard(vec![0])).unwrap();
+        assert_eq!(game.phase, Phase::Play);
+        assert_eq!(game.current_player, 1);
+        assert_eq!(events.last(), Some(&GameEvent::TurnPassed { player: 1 }));
+    }
+
+    #[test]
+    fn test_defeating_enemy_keeps_turn() {
+        let mut game = Game::new_with_seed(3, 12);
+        game.current_enemy = Some(Enemy::new(Card::new(Suit::Spades, Rank::Jack))); // 20 HP
+        game.players[0].hand[0] = Card::new(Suit::Clubs, Rank::Ten);
+
+        game.apply(GameAction::Play(vec![0])).unwrap();
+        assert_eq!(game.current_player, 0);
+        assert!(!game
+            .events
+            .iter()
+            .any(|e| matches!(e, GameEvent::TurnPassed { .. })));
+    }
+
+    #[test]
+    fn test_diamonds_draw_around_the_table() {
+        let mut game = Game::new_with_seed(3, 13);
+        game.current_enemy = Some(Enemy::new(Card::new(Suit::Spades, Rank::Jack)));
+        game.players[0].hand = vec![Card::new(Suit::Diamonds, Rank::Five)];
+        game.players[2].hand.truncate(4);
+
+        game.play_cards(vec![0]).unwrap();
+
+        // Draws go 0, 2, 0, 2, 0: Player 2 is full and Player 3 fills up after two
+        assert_eq!(game.players[0].hand.len(), 3);
+        assert_eq!(game.players[1].hand.len(), 6);
+        assert_eq!(game.players[2].hand.len(), 6);
+        assert!(game.events.contains(&GameEvent::Drew { count: 5 }));
+    }
+
+    /// Yield around the table with shields high enough that nobody has to discard
+    fn yield_in_turn(players: usize) {
+        let mut game = Game::new_with_seed(players, 21);
+        game.shield_value = 20;
+
+        // Everyone but the last player may yield in a row
+        for seat in 0..players - 1 {
+            assert_eq!(game.current_player, seat);
+            assert!(game.can_yield());
+            game.apply(GameAction::Yield).unwrap();
+        }
+        assert_eq!(game.current_player, players - 1);
+        assert!(!game.can_yield());
+        assert_eq!(
+            game.apply(GameAction::Yield),
+            Err(RuleError::EveryoneElseYielded)
+        );
+        assert_eq!(game.current_player, players - 1);
+        assert_eq!(game.phase, Phase::Play);
+
+        // Playing a card lets the next player yield again
+        let card = game.player().hand.iter().position(|c| !c.is_jester());
+        game.apply(GameAction::Play(vec![card.unwrap()])).unwrap();
+        assert_eq!(game.current_player, 0);
+        assert!(game.can_yield());
+        game.apply(GameAction::Yield).unwrap();
+    }
+
+    #[test]
+    fn test_two_players_cannot_both_yield() {
+        yield_in_turn(2);
+    }
+
+    #[test]
+    fn test_three_players_cannot_all_yield() {
+        yield_in_turn(3);
+    }
+
+    #[test]
+    fn test_four_players_cannot_all_yield() {
+        yield_in_turn(4);
+    }
+
+    #[test]
+    fn test_solo_player_can_always_yield() {
+        let mut game = Game::new_with_seed(1, 22);
+        game.shield_value = 20;
+        for _ in 0..3 {
+            game.apply(GameAction::Yield).unwrap();
+        }
+        assert!(game.can_yield());
+    }
+
+    #[test]
+    fn test_multiplayer_jester_chooses_next_player() {
+        let mut game = Game::new_with_seed(3, 14);
+        game.players[0].hand[0] = Card::new(Suit::Hearts, Rank::Jester);
+
+        let events = game.apply(GameAction::Play(vec![0])).unwrap();
+        assert_eq!(events, vec![GameEvent::JesterPlayed]);
+        assert_eq!(game.phase, Phase::ChooseNextPlayer);
+        assert_eq!(game.current_player, 0);
+
+        // Nothing else can happen until someone is chosen
+        assert_eq!(
+            game.apply(GameAction::Yield),
+            Err(RuleError::WrongPhase {
+                phase: Phase::ChooseNextPlayer
+            })
+        );
+        assert_eq!(
+            game.apply(GameAction::ChooseNextPlayer(3)),
+            Err(RuleError::InvalidPlayer(3))
+        );
+
+        let events = game.apply(GameAction::ChooseNextPlayer(2)).unwrap();
+        assert_eq!(events, vec![GameEvent::NextPlayerChosen { player: 2 }]);
+        assert_eq!(game.phase, Phase::Play);
+        assert_eq!(game.current_player, 2);
+    }
+
+    #[test]
+    fn test_jester_player_can_choose_themselves() {
+        let mut game = Game::new_with_seed(2, 16);
+        game.players[0].hand[0] = Card::new(Suit::Hearts, Rank::Jester);
+        game.apply(GameAction::Play(vec![0])).unwrap();
+
+        game.apply(GameAction::ChooseNextPlayer(0)).unwrap();
+        assert_eq!(game.current_player, 0);
+        assert_eq!(game.phase, Phase::Play);
+    }
+
+    #[test]
+    fn test_choose_next_player_outside_jester_phase_fails() {
+        let mut game = Game::new_with_seed(2, 17);
+        assert_eq!(
+            game.apply(GameAction::ChooseNextPlayer(1)),
+            Err(RuleError::WrongPhase { phase: Phase::Play })
+        );
+
+        // Solo Jesters never ask who goes next
+        let mut solo = Game::new_solo_with_seed(17);
+        solo.players[0].hand[0] = Card::new(Suit::Hearts, Rank::Jester);
+        solo.apply(GameAction::Play(vec![0])).unwrap();
+        assert_eq!(solo.phase, Phase::Play);
+    }
+
+    #[test]
+    fn test_solo_jester_power_rejected_in_multiplayer() {
+        let mut game = Game::new_with_seed(4, 15);
+        assert_eq!(
+            game.apply(GameAction::UseSoloJester),
+            Err(RuleError::SoloOnly)
+        );
+        assert_eq!(game.victory_rank(), None);
+    }
+
+    #[test]
+    fn test_exact_kill_event_stream() {
+        // Test that every rule step of a capturing play is reported in order
+        let mut game = Game::new_solo_with_seed(3);
+        let next_enemy = *game.castle_deck.cards.last().unwrap();
+        let jack = Card::new(Suit::Hearts, Rank::Jack);
+        game.current_enemy = Some(Enemy::new(jack));
+        game.players[0].hand.clear();
+        game.players[0].hand.push(Card::new(Suit::Clubs, Rank::Ten));
+        let ten = game.players[0].hand[0];
+
+        let events = game.apply(GameAction::Play(vec![0])).unwrap();
+        assert_eq!(
+            events,
+            vec![
+                GameEvent::CardsPlayed {
+                    cards: vec![ten],
+                    attack: 10
+                },
+                GameEvent::DamageDoubled,
+                GameEvent::DamageDealt {
+                    damage: 20,
+                    total: 20,
+                    max_hp: 20
+                },
+                GameEvent::EnemyCaptured { enemy: jack },
+                GameEvent::EnemyRevealed { enemy: next_enemy },
+            ]
+        );
+        assert_eq!(&game.events[game.events.len() - 5..], &events[..]);
+        assert_eq!(game.tavern_deck.face_up_cards(), &[jack]);
+    }
+
+    #[test]
+    fn test_log_is_rendered_from_events() {
+        let mut game = Game::new_solo_with_seed(3);
+        game.current_enemy = Some(Enemy::new(Card::new(Suit::Spades, Rank::Jack)));
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Spades, Rank::Four));
+
+        let events = game.apply(GameAction::Play(vec![0])).unwrap();
+        assert_eq!(events[1], GameEvent::PowerBlocked { suit: Suit::Spades });
+
+        let rendered: Vec<String> = events.iter().map(|e| e.to_string()).collect();
+        let log_tail = &game.game_log[game.game_log.len() - rendered.len()..];
+        for (line, text) in log_tail.iter().zip(&rendered) {
+            assert!(line.ends_with(text.as_str()), "{} != {}", line, text);
+        }
+        assert_eq!(rendered[1], "Spades power blocked by immunity");
+    }
+
     // ===== COMPREHENSIVE GAME RULES TESTS =====
 
     #[test]
@@ -698,7 +1765,7 @@ mod tests {
         use crate::card::Rank;
         use crate::deck::Deck;
 
-        let castle = Deck::create_castle_deck();
+        let castle = Deck::create_castle_deck(&mut GameRng::seed_from_u64(1));
         assert_eq!(castle.len(), 12, "Castle deck should have 12 enemies");
 
         // First 4 cards drawn should be Jacks (top layer)
@@ -727,7 +1794,7 @@ mod tests {
         use crate::card::Rank;
         use crate::deck::Deck;
 
-        let tavern = Deck::create_tavern_deck(0); // 0 Jesters for solo
+        let tavern = Deck::create_tavern_deck(0, &mut GameRng::seed_from_u64(1)); // 0 Jesters for solo
         assert_eq!(
             tavern.len(),
             40,
@@ -784,8 +1851,10 @@ mod tests {
         let tavern_before = game.tavern_deck.len();
 
         // Play 5 of Hearts -> heal 5 cards
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Five));
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Five));
         let result = game.play_cards(vec![0]);
 
         assert!(result.is_ok());
@@ -807,14 +1876,16 @@ mod tests {
         let mut game = Game::new_solo();
         game.current_enemy = Some(Enemy::new(Card::new(Suit::Spades, Rank::Jack)));
 
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Diamonds, Rank::Five));
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Diamonds, Rank::Five));
 
         let result = game.play_cards(vec![0]);
         assert!(result.is_ok());
 
         // Should draw 5 cards (hand was 1, now should be 1 - 1 (played) + 5 (drawn) = 5)
-        assert_eq!(game.player.hand.len(), 5, "Should draw 5 cards");
+        assert_eq!(game.players[0].hand.len(), 5, "Should draw 5 cards");
     }
 
     #[test]
@@ -824,9 +1895,13 @@ mod tests {
         game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack)));
 
         // Turn 1: Play 5 of Spades -> shield = 5
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Spades, Rank::Five));
-        game.player.hand.push(Card::new(Suit::Spades, Rank::Three));
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Spades, Rank::Five));
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Spades, Rank::Three));
         let result = game.play_cards(vec![0]);
         assert!(result.is_ok());
         assert_eq!(game.shield_value, 5);
@@ -844,8 +1919,10 @@ mod tests {
         game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack)));
 
         // Play Hearts card against Hearts enemy -> power blocked
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Five));
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Five));
         game.discard_pile.push(Card::new(Suit::Clubs, Rank::Two));
         let tavern_before = game.tavern_deck.len();
 
@@ -867,9 +1944,11 @@ mod tests {
         game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack)));
 
         // Ace + 5 = 6 attack
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Clubs, Rank::Ace));
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Five));
+        game.players[0].hand.clear();
+        game.players[0].hand.push(Card::new(Suit::Clubs, Rank::Ace));
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Five));
 
         let result = game.play_cards(vec![0, 1]);
         assert!(result.is_ok());
@@ -885,10 +1964,14 @@ mod tests {
         let mut game = Game::new_solo();
 
         // Valid combo: 3 + 3 + 3 = 9 <= 10
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Three));
-        game.player.hand.push(Card::new(Suit::Clubs, Rank::Three));
-        game.player
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Three));
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Clubs, Rank::Three));
+        game.players[0]
             .hand
             .push(Card::new(Suit::Diamonds, Rank::Three));
 
@@ -896,14 +1979,77 @@ mod tests {
         assert!(result.is_ok(), "3+3+3 should be valid combo");
 
         // Invalid combo: 6 + 6 = 12 > 10
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Six));
-        game.player.hand.push(Card::new(Suit::Clubs, Rank::Six));
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Six));
+        game.players[0].hand.push(Card::new(Suit::Clubs, Rank::Six));
 
         let result = game.validate_play(&[0, 1]);
         assert!(result.is_err(), "6+6=12 should be invalid (>10)");
     }
 
+    #[test]
+    fn test_validate_play_error_kinds() {
+        let mut game = Game::new_solo_with_seed(5);
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Six)); // 0
+        game.players[0].hand.push(Card::new(Suit::Clubs, Rank::Six)); // 1
+        game.players[0].hand.push(Card::new(Suit::Clubs, Rank::Ace)); // 2
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Jester)); // 3
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Spades, Rank::Two)); // 4
+
+        assert_eq!(game.validate_play(&[]), Err(RuleError::EmptySelection));
+        assert_eq!(game.validate_play(&[9]), Err(RuleError::InvalidIndex(9)));
+        assert_eq!(game.validate_play(&[4, 4]), Err(RuleError::InvalidIndex(4)));
+        assert_eq!(game.validate_play(&[3, 4]), Err(RuleError::JesterNotAlone));
+        assert_eq!(
+            game.validate_play(&[2, 0, 4]),
+            Err(RuleError::AceWithTooMany)
+        );
+        assert_eq!(game.validate_play(&[0, 4]), Err(RuleError::MixedRankCombo));
+        assert_eq!(
+            game.validate_play(&[0, 1]),
+            Err(RuleError::ComboOverTen { total: 12 })
+        );
+    }
+
+    #[test]
+    fn test_discard_errors() {
+        let mut game = Game::new_solo_with_seed(5);
+        game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack)));
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Clubs, Rank::Eight));
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Clubs, Rank::Three));
+
+        assert_eq!(
+            game.apply(GameAction::Discard(vec![0])),
+            Err(RuleError::WrongPhase { phase: Phase::Play })
+        );
+
+        game.apply(GameAction::Yield).unwrap();
+        assert_eq!(
+            game.apply(GameAction::Discard(vec![0])),
+            Err(RuleError::NotEnoughDiscardValue { need: 10, have: 8 })
+        );
+        // The same card cannot be counted twice
+        assert_eq!(
+            game.apply(GameAction::Discard(vec![0, 0])),
+            Err(RuleError::InvalidIndex(0))
+        );
+        assert!(game.apply(GameAction::Discard(vec![0, 1])).is_ok());
+    }
+
     #[test]
     fn test_exact_damage_capture() {
         // Test exact damage places enemy on top of tavern deck
@@ -911,8 +2057,8 @@ mod tests {
         game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack))); // 20 HP
 
         // Deal exactly 20 damage
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Clubs, Rank::Ten)); // 10 * 2 = 20
+        game.players[0].hand.clear();
+        game.players[0].hand.push(Card::new(Suit::Clubs, Rank::Ten)); // 10 * 2 = 20
 
         let tavern_before = game.tavern_deck.len();
         let result = game.play_cards(vec![0]);
@@ -933,14 +2079,20 @@ mod tests {
         game.current_enemy = Some(Enemy::new(Card::new(Suit::Hearts, Rank::Jack))); // Attack 10
 
         // Give player cards totaling >= 10
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Five));
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Five));
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Two));
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Five));
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Five));
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Two));
 
         // Check if player can survive 10 damage
         assert!(
-            game.player.can_survive(10),
+            game.players[0].can_survive(10),
             "Player should be able to survive"
         );
 
@@ -962,9 +2114,13 @@ mod tests {
         }
 
         // Play Ace♥ + Ace♦ for smaller attack value but still activates both powers
-        game.player.hand.clear();
-        game.player.hand.push(Card::new(Suit::Hearts, Rank::Ace)); // 1
-        game.player.hand.push(Card::new(Suit::Diamonds, Rank::Ace)); // 1
+        game.players[0].hand.clear();
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Hearts, Rank::Ace)); // 1
+        game.players[0]
+            .hand
+            .push(Card::new(Suit::Diamonds, Rank::Ace)); // 1
         let _tavern_before = game.tavern_deck.len();
         let discard_before = game.discard_pile.len();
 
@@ -981,7 +2137,7 @@ mod tests {
         );
         // We played 2 cards (hand was 2), drew 2, so hand should be 2
         assert_eq!(
-            game.player.hand.len(),
+            game.players[0].hand.len(),
             2,
             "Diamonds should have drawn 2 cards"
         );
@@ -0,0 +1,38 @@
+//! Rules engine for Kingslayer, a Regicide implementation.
+//!
+//! The engine has no terminal dependencies so bots and tools can drive a
+//! [`Game`] directly; the `kingslayer` binary is a Ratatui frontend over it.
+
+pub mod action;
+pub mod advisor;
+pub mod bot;
+pub mod card;
+pub mod chat;
+pub mod deck;
+pub mod enemy;
+pub mod error;
+pub mod event;
+pub mod game;
+pub mod mcts;
+pub mod net;
+pub mod pipe;
+pub mod player;
+pub mod replay;
+pub mod save;
+pub mod sim;
+pub mod solver;
+pub mod view;
+
+pub use action::GameAction;
+pub use advisor::Hint;
+pub use bot::Bot;
+pub use card::{Card, Rank, Suit};
+pub use chat::{ChatMessage, ChatMode};
+pub use deck::Deck;
+pub use enemy::Enemy;
+pub use error::{ChatError, RuleError};
+pub use event::GameEvent;
+pub use game::{Game, GameRng, GameState, Phase, VictoryRank};
+pub use player::Player;
+pub use replay::Replay;
+pub use view::{GameView, PlayerView};
@@ -1,48 +1,372 @@
-mod card;
-mod deck;
-mod enemy;
-mod game;
-mod player;
+mod chat_box;
+mod cli;
+mod client_app;
+mod replay_viewer;
 mod ui;
 
+use chat_box::{ChatBox, ChatInput};
+use cli::{
+    BotServerOptions, Command, HostOptions, JoinOptions, Options, SimulateOptions, SolveOptions,
+};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use game::{Game, GameState};
+use kingslayer::mcts::Budget;
+use kingslayer::net::{self, Client, Host, TableSettings};
+use kingslayer::solver::{Solver, Verdict};
+use kingslayer::{
+    advisor, chat, replay, save, ChatMessage, ChatMode, Game, GameAction, GameState, GameView,
+    Hint, Phase, Replay, RuleError, VictoryRank,
+};
+use kingslayer::{bot, pipe, sim, Bot};
 use ratatui::{backend::CrosstermBackend, Terminal};
+use replay_viewer::ReplayViewer;
 use std::io;
+use std::time::Duration;
 
+/// Frontend-only states; the turn phase itself lives in `Game::phase`
 enum AppState {
     Playing,
-    DiscardPhase { required_damage: u8 },
-    Victory,
-    Defeat,
+    Lobby, // Hosting: gathering players before the game is dealt
+    ResumePrompt,
     RestartConfirmation,
     QuitConfirmation,
 }
 
 struct App {
     game: Game,
+    players: usize, // Player count from --players, the lobby or a save, reused on restart
+    seed: Option<u64>, // Fixed seed from --seed, reused on restart until a save is resumed
+    mark_assisted: bool, // Undoing marks the game as assisted (off with --free-undo)
+    undo_stack: Vec<Game>, // Snapshots taken before each accepted action
+    redo_stack: Vec<Game>, // Snapshots popped by undo, until a new action is taken
     selected_cards: Vec<usize>,
+    error_cards: Vec<usize>, // Cards flagged by the last rejected action
+    hint: Option<Hint>,      // Advice shown after pressing '?', until the game moves on
+    notice: Option<String>,  // Hosting: our own messages, kept out of the shared log
     state: AppState,
+    resume_offered: bool, // The resume prompt is still waiting for an answer
     show_help: bool,
+    pass_screen: bool,    // Hot-seat: hide the table until the next player is ready
+    player_cursor: usize, // Highlighted entry when choosing who goes next after a Jester
+    local_seat: Option<usize>, // Seat played at this terminal; None for solo/hot-seat
+    bots: Vec<(usize, Box<dyn Bot>)>, // Hot-seat bots by seat (--bots); the host seats LAN bots
+    host: Option<Host>,   // Clients of a LAN game hosted here
+    lobby_cursor: usize,  // Lobby member highlighted for the host to move
+    chat_mode: ChatMode,  // Hot-seat chat rules; LAN games use the host's settings
+    chat_log: Vec<ChatMessage>, // Hot-seat chat; LAN chat is kept by the host
+    chat_box: Option<ChatBox>, // Message being written
+    chat_error: Option<String>, // Why the last message was blocked
     log_scroll_offset: usize,
     guide_scroll_offset: usize,
     help_scroll_offset: usize,
 }
 
 impl App {
-    fn new() -> Self {
-        Self {
-            game: Game::new_solo(),
+    fn new(options: &Options) -> Self {
+        let game = new_game(options.players, options.seed);
+        // Bots take the last seats, so the first player is always a person
+        let first_bot = options.players - options.bots;
+        let bots = make_bots(&options.bot, options.bots, options.seed)
+            .into_iter()
+            .enumerate()
+            .map(|(i, bot)| (first_bot + i, bot))
+            .collect();
+        let mut app = Self {
+            pass_screen: first_bot > 1,
+            game,
+            player_cursor: 0,
+            local_seat: None,
+            bots,
+            host: None,
+            lobby_cursor: 0,
+            chat_mode: if options.strict_chat {
+                ChatMode::Strict
+            } else {
+                ChatMode::Free
+            },
+            chat_log: Vec::new(),
+            chat_box: None,
+            chat_error: None,
+            players: options.players,
+            seed: options.seed,
+            mark_assisted: !options.free_undo,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
             selected_cards: Vec::new(),
+            error_cards: Vec::new(),
+            hint: None,
+            notice: None,
             state: AppState::Playing,
+            resume_offered: false,
             show_help: false,
             log_scroll_offset: 0,
             guide_scroll_offset: 0,
             help_scroll_offset: 0,
+        };
+        app.name_bot_seats();
+        app
+    }
+
+    /// Hot-seat: put the bots' names on their seats, e.g. after dealing a new game
+    fn name_bot_seats(&mut self) {
+        for (i, (seat, bot)) in self.bots.iter().enumerate() {
+            self.game.players[*seat].name = format!("Bot {} ({})", i + 1, bot.name());
+        }
+    }
+
+    /// Hot-seat: let the bots play while it is one of their turns
+    fn take_bot_turns(&mut self) {
+        let mut seated: Vec<(usize, &mut dyn Bot)> = self
+            .bots
+            .iter_mut()
+            .map(|(seat, bot)| (*seat, &mut **bot as &mut dyn Bot))
+            .collect();
+        bot::take_turns(&mut self.game, &mut seated);
+    }
+
+    /// Host a LAN game, opening the lobby; the real deal waits for `start_lan_game`
+    fn hosting(host: Host, options: &HostOptions) -> Self {
+        let mut app = Self::new(&Options {
+            players: 2,
+            seed: options.seed,
+            resume: false,
+            free_undo: false,
+            strict_chat: options.strict_chat,
+            bots: 0,
+            bot: String::new(),
+        });
+        app.pass_screen = false;
+        app.local_seat = Some(0);
+        app.host = Some(host);
+        app.state = AppState::Lobby;
+        app
+    }
+
+    /// Deal a game for everyone in the lobby, if they are all ready
+    fn start_lan_game(&mut self) {
+        let Some(host) = &mut self.host else {
+            return;
+        };
+        if !host.lobby().can_start() {
+            return;
+        }
+
+        self.players = host.table_size();
+        self.game = new_game(self.players, self.seed);
+        self.local_seat = Some(host.start(&mut self.game));
+        self.state = AppState::Playing;
+        self.reset_log_scroll();
+    }
+
+    /// Move the highlighted lobby member one seat up or down
+    fn move_lobby_member(&mut self, up: bool) {
+        let Some(host) = &mut self.host else {
+            return;
+        };
+        let from = self.lobby_cursor;
+        let to = if up {
+            from.saturating_sub(1)
+        } else {
+            (from + 1).min(host.table_size() - 1)
+        };
+        host.move_member(from, to);
+        self.lobby_cursor = to;
+    }
+
+    /// Let spectators see every hand, or none
+    fn toggle_spectator_hands(&mut self) {
+        if let Some(host) = &mut self.host {
+            let mut settings = host.settings();
+            settings.spectators_see_hands = !settings.spectators_see_hands;
+            host.set_settings(settings);
+        }
+    }
+
+    /// Switch the table between free and strict chat
+    fn toggle_chat_mode(&mut self) {
+        if let Some(host) = &mut self.host {
+            let mut settings = host.settings();
+            settings.chat_mode = match settings.chat_mode {
+                ChatMode::Free => ChatMode::Strict,
+                ChatMode::Strict => ChatMode::Free,
+            };
+            host.set_settings(settings);
+        }
+    }
+
+    fn move_lobby_cursor(&mut self, up: bool) {
+        let size = self.host.as_ref().map_or(1, |host| host.table_size());
+        self.lobby_cursor = if up {
+            self.lobby_cursor.saturating_sub(1)
+        } else {
+            (self.lobby_cursor + 1).min(size - 1)
+        };
+    }
+
+    /// What this terminal may see of the game
+    fn view(&self) -> GameView {
+        match self.local_seat {
+            Some(seat) => GameView::for_seat(&self.game, seat),
+            None => GameView {
+                seed: Some(self.game.seed),
+                ..GameView::for_seat(&self.game, self.game.current_player)
+            },
+        }
+    }
+
+    /// The chat pane, for multiplayer games only
+    fn chat_panel(&self) -> Option<ui::ChatPanel<'_>> {
+        let (messages, mode) = match &self.host {
+            Some(host) => (host.chat(), host.settings().chat_mode),
+            None if !self.game.is_solo() => (self.chat_log.as_slice(), self.chat_mode),
+            None => return None,
+        };
+        Some(ui::ChatPanel {
+            messages,
+            mode,
+            chat_box: self.chat_box.as_ref(),
+            error: self.chat_error.as_deref(),
+        })
+    }
+
+    /// Chat in multiplayer games, otherwise the game guide
+    fn info_pane(&self) -> ui::InfoPane<'_> {
+        match self.chat_panel() {
+            Some(chat) => ui::InfoPane::Chat(chat),
+            None => ui::InfoPane::Guide(self.guide_scroll_offset),
+        }
+    }
+
+    /// Start writing a chat message, in multiplayer games
+    fn open_chat(&mut self) {
+        if let Some(mode) = self.chat_panel().map(|chat| chat.mode) {
+            self.chat_box = Some(ChatBox::open(mode));
+            self.chat_error = None;
+        }
+    }
+
+    /// Post a chat message as this terminal's player, keeping the box open
+    /// if the message breaks the rules
+    fn send_chat(&mut self, text: &str) {
+        let result = match &mut self.host {
+            Some(host) => host.say(text),
+            None => {
+                chat::check_message(self.chat_mode, self.game.players.len(), text).map(|text| {
+                    let from = self.game.player().name.clone();
+                    self.chat_log.push(ChatMessage { from, text });
+                })
+            }
+        };
+        match result {
+            Ok(()) => {
+                self.chat_box = None;
+                self.chat_error = None;
+            }
+            Err(e) => self.chat_error = Some(e.to_string()),
+        }
+    }
+
+    /// Take in clients' joins, lobby changes and actions
+    fn poll_network(&mut self) {
+        let Some(host) = &mut self.host else {
+            return;
+        };
+
+        let was_over = self.game.is_over();
+        let changed = host.poll(&mut self.game);
+        // Someone may have left the lobby from under the cursor
+        self.lobby_cursor = self.lobby_cursor.min(host.table_size() - 1);
+
+        if changed {
+            self.hint = None;
+            self.notice = None;
+            self.reset_log_scroll();
+            if self.game.is_over() && !was_over {
+                self.record_replay();
+            }
+        }
+    }
+
+    /// Tell the player at this terminal something. A hosted game's log goes
+    /// to every client, so the host's own messages are shown by the prompt.
+    fn notify<S: Into<String>>(&mut self, message: S) {
+        if self.host.is_some() {
+            self.notice = Some(message.into());
+        } else {
+            self.game.log(message);
+            self.reset_log_scroll();
+        }
+    }
+
+    /// Start from a saved game instead of a fresh deal
+    fn resume(&mut self, game: Game) {
+        // Restarting deals a fresh game for the saved table, not the command line's
+        self.players = game.players.len();
+        self.seed = None;
+        let players = self.players;
+        self.bots.retain(|(seat, _)| *seat < players);
+        self.game = game;
+        self.name_bot_seats();
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.selected_cards.clear();
+        self.error_cards.clear();
+        self.hint = None;
+        self.state = AppState::Playing;
+        self.game.log("Resumed saved game");
+        // The save may have stopped on what is now a bot's turn
+        self.take_bot_turns();
+        if self.game.is_over() {
+            self.record_replay();
+        }
+        self.pass_screen = self.game.players.len() - self.bots.len() > 1;
+        self.reset_log_scroll();
+    }
+
+    /// Write the current game to the save file
+    fn save_game(&mut self) {
+        if self.host.is_some() {
+            self.notify("LAN games can't be saved, but a replay is recorded");
+            return;
+        }
+        match save::save_game(&self.game) {
+            Ok(path) => self.notify(format!("Game saved to {}", path.display())),
+            Err(e) => self.notify(format!("Error: Could not save game: {}", e)),
+        }
+    }
+
+    /// Auto-save on quit; a finished game has nothing left to resume
+    fn save_on_quit(&mut self) -> io::Result<()> {
+        if self.resume_offered {
+            // Leave the saved game that was on offer in place of the unplayed deal
+            Ok(())
+        } else if self.host.is_some() {
+            // LAN games can't be resumed without their players, so only record them
+            if !self.game.is_over() {
+                self.record_replay();
+            }
+            Ok(())
+        } else if self.game.is_over() {
+            save::delete_saved_game()
+        } else {
+            // The game goes on after a resume, so it is recorded once it ends
+            save::save_game(&self.game).map(|_| ())
+        }
+    }
+
+    /// Record the game so far to the replay directory
+    fn record_replay(&mut self) {
+        if self.game.actions.is_empty() {
+            return;
+        }
+        match replay::save_replay(&self.game) {
+            Ok(path) => self.notify(format!("Replay saved to {}", path.display())),
+            Err(e) => self.notify(format!("Error: Could not save replay: {}", e)),
         }
     }
 
@@ -93,8 +417,24 @@ impl App {
     }
 
     fn restart_game(&mut self) {
-        self.game = Game::new_solo();
+        // Finished games were recorded when they ended
+        if !self.game.is_over() {
+            self.record_replay();
+        }
+        self.game = new_game(self.players, self.seed);
+        self.name_bot_seats();
+        self.take_bot_turns();
+        self.pass_screen =
+            self.local_seat.is_none() && self.game.players.len() - self.bots.len() > 1;
+        if let Some(host) = &mut self.host {
+            host.name_seats(&mut self.game);
+            host.broadcast(&self.game);
+        }
+        self.undo_stack.clear();
+        self.redo_stack.clear();
         self.selected_cards.clear();
+        self.error_cards.clear();
+        self.hint = None;
         self.state = AppState::Playing;
         self.log_scroll_offset = 0;
         self.guide_scroll_offset = 0;
@@ -102,10 +442,12 @@ impl App {
     }
 
     fn toggle_card_selection(&mut self, index: usize) {
-        if index >= self.game.player.hand_size() {
+        // A host picks from their own hand, whoever's turn it is
+        if index >= self.view().hand().len() {
             return;
         }
 
+        self.error_cards.clear();
         if let Some(pos) = self.selected_cards.iter().position(|&i| i == index) {
             self.selected_cards.remove(pos);
         } else {
@@ -113,135 +455,217 @@ impl App {
         }
     }
 
-    fn play_selected_cards(&mut self) {
-        if self.selected_cards.is_empty() {
-            self.game.log("No cards selected");
-            self.reset_log_scroll();
+    /// Send an action to the game, clearing the selection if it was accepted
+    fn apply(&mut self, action: GameAction) {
+        // In a LAN game, wait for our own turn, and for dropped players to come back
+        if self
+            .local_seat
+            .is_some_and(|seat| seat != self.game.current_player)
+            || self.host.as_ref().is_some_and(|host| host.is_paused())
+        {
             return;
         }
 
-        // Sort indices for proper removal
-        self.selected_cards.sort_unstable();
-
-        match self.game.play_cards(self.selected_cards.clone()) {
-            Ok(enemy_defeated) => {
+        let snapshot = self.game.clone();
+        match self.game.apply(action) {
+            Ok(_) => {
+                self.take_bot_turns();
+                // Hide the hand while the device changes hands
+                if self.local_seat.is_none()
+                    && self.game.current_player != snapshot.current_player
+                    && !self.game.is_over()
+                {
+                    self.pass_screen = true;
+                }
+                // Default the Jester choice to whoever is next around the table
+                if self.game.phase == Phase::ChooseNextPlayer {
+                    self.player_cursor = (self.game.current_player + 1) % self.game.players.len();
+                }
+                if self.game.is_solo() {
+                    self.undo_stack.push(snapshot);
+                    self.redo_stack.clear();
+                }
                 self.selected_cards.clear();
-                self.reset_log_scroll();
-
-                // Check game state
-                match self.game.game_state {
-                    GameState::Victory => {
-                        self.state = AppState::Victory;
-                        return;
-                    }
-                    GameState::Defeat(_) => {
-                        self.state = AppState::Defeat;
-                        return;
-                    }
-                    _ => {}
+                self.error_cards.clear();
+                self.hint = None;
+                self.notice = None;
+                if self.game.is_over() {
+                    self.record_replay();
                 }
-
-                // Only enemy attacks if no enemy was defeated AND Jester was not played
-                // (If enemy was defeated, new enemy appears and waits for player's turn)
-                // (If Jester was played, skip Step 4 per rules)
-                if !enemy_defeated && !self.game.jester_played_this_turn {
-                    // Transition to discard phase (enemy attack)
-                    if let Ok(damage) = self.game.enemy_attack() {
-                        self.reset_log_scroll();
-                        if damage > 0 {
-                            // Check if player can survive
-                            if !self.game.player.can_survive(damage) {
-                                self.state = AppState::Defeat;
-                                self.game.game_state =
-                                    GameState::Defeat("Cannot survive enemy attack!".to_string());
-                            } else {
-                                self.state = AppState::DiscardPhase {
-                                    required_damage: damage,
-                                };
-                            }
-                        }
-                        // If damage is 0, continue to next turn
-                    }
+                if let Some(host) = &mut self.host {
+                    host.broadcast(&self.game);
                 }
             }
             Err(e) => {
-                self.game.log(format!("Error: {}", e));
-                self.reset_log_scroll();
+                self.error_cards = self.offending_cards(&e);
+                self.notify(format!("Error: {}", e));
             }
         }
+        self.reset_log_scroll();
     }
 
-    fn yield_turn(&mut self) {
-        if self.game.yield_turn().is_ok() {
-            self.reset_log_scroll();
-            // Transition to discard phase
-            if let Ok(damage) = self.game.enemy_attack() {
-                self.reset_log_scroll();
-                if damage > 0 {
-                    if !self.game.player.can_survive(damage) {
-                        self.state = AppState::Defeat;
-                        self.game.game_state =
-                            GameState::Defeat("Cannot survive enemy attack!".to_string());
-                    } else {
-                        self.state = AppState::DiscardPhase {
-                            required_damage: damage,
-                        };
-                    }
-                }
+    fn move_player_cursor_up(&mut self) {
+        let count = self.game.players.len();
+        self.player_cursor = (self.player_cursor + count - 1) % count;
+    }
+
+    fn move_player_cursor_down(&mut self) {
+        self.player_cursor = (self.player_cursor + 1) % self.game.players.len();
+    }
+
+    /// Step back to the state before the last accepted action
+    fn undo(&mut self) {
+        if !self.game.is_solo() {
+            self.notify("Undo is only available in solo games");
+            return;
+        }
+        match self.undo_stack.pop() {
+            Some(previous) => {
+                let current = std::mem::replace(&mut self.game, previous);
+                self.redo_stack.push(current);
+                self.after_undo_or_redo("Undid last action");
             }
+            None => self.notify("Nothing to undo"),
         }
+        self.reset_log_scroll();
     }
 
-    fn discard_selected_cards(&mut self, _required: u8) {
-        if self.selected_cards.is_empty() {
-            self.game.log("No cards selected to discard");
-            self.reset_log_scroll();
-            return;
+    /// Re-apply the last undone action
+    fn redo(&mut self) {
+        match self.redo_stack.pop() {
+            Some(next) => {
+                let current = std::mem::replace(&mut self.game, next);
+                self.undo_stack.push(current);
+                self.after_undo_or_redo("Redid action");
+            }
+            None => self.notify("Nothing to redo"),
         }
+        self.reset_log_scroll();
+    }
 
-        self.selected_cards.sort_unstable();
+    /// Restored snapshots lose the selection and, unless undo is free, their rank
+    fn after_undo_or_redo(&mut self, message: &str) {
+        self.selected_cards.clear();
+        self.error_cards.clear();
+        self.hint = None;
+        if self.mark_assisted && !self.game.assisted {
+            self.game.assisted = true;
+            self.game
+                .log(format!("{} (game marked as assisted, unranked)", message));
+        } else {
+            self.game.log(message);
+        }
+    }
 
-        match self.game.discard_to_survive(self.selected_cards.clone()) {
-            Ok(_) => {
-                self.selected_cards.clear();
-                self.reset_log_scroll();
-                self.state = AppState::Playing;
-                self.game.log("Survived enemy attack! New turn begins.");
-                self.reset_log_scroll();
-            }
-            Err(e) => {
-                self.game.log(format!("Error: {}", e));
-                self.reset_log_scroll();
-            }
+    /// Selected cards to flag in the hand after a rejected action
+    fn offending_cards(&self, error: &RuleError) -> Vec<usize> {
+        let hand = &self.game.player().hand;
+        let selected = self.selected_cards.iter().copied();
+        match error {
+            RuleError::InvalidIndex(index) => vec![*index],
+            RuleError::JesterNotAlone => selected
+                .filter(|&i| hand.get(i).is_some_and(|c| c.is_jester()))
+                .collect(),
+            RuleError::AceWithTooMany => selected
+                .filter(|&i| hand.get(i).is_some_and(|c| c.is_companion()))
+                .collect(),
+            e if e.concerns_selection() => selected.collect(),
+            _ => Vec::new(),
         }
     }
 
-    fn use_jester(&mut self) {
-        match self.game.use_jester() {
-            Ok(_) => {
-                self.reset_log_scroll();
-            }
-            Err(e) => {
-                self.game.log(format!("Error: {}", e));
-                self.reset_log_scroll();
+    /// Select the least wasteful cards that cover the enemy attack
+    fn select_suggested_discard(&mut self) {
+        let view = self.view();
+        if !matches!(view.phase, Phase::Discard { .. }) || !view.is_my_turn() {
+            return;
+        }
+        match view.suggested_discard() {
+            Some(cards) => {
+                self.selected_cards = cards;
+                self.error_cards.clear();
             }
+            None => self.notify("No discard covers the attack"),
+        }
+    }
+
+    /// Ask the advisor for the best move, highlighting its cards in the hand
+    fn show_hint(&mut self) {
+        self.hint = advisor::hint(&self.view());
+        if self.hint.is_none() {
+            self.notify("No hint right now");
         }
     }
 
+    fn play_selected_cards(&mut self) {
+        // Sort indices for proper removal
+        self.selected_cards.sort_unstable();
+        self.apply(GameAction::Play(self.selected_cards.clone()));
+    }
+
+    fn discard_selected_cards(&mut self) {
+        self.selected_cards.sort_unstable();
+        self.apply(GameAction::Discard(self.selected_cards.clone()));
+    }
+
     fn get_action_prompt(&self) -> String {
         match &self.state {
-            AppState::Playing => {
-                "⚔️  ATTACK: Select cards (1-8) and press Enter to play, or Space to yield"
-                    .to_string()
+            AppState::Playing if self.game.is_over() => {
+                let result = match (&self.game.game_state, self.game.victory_rank()) {
+                    (_, Some(rank)) => format!("🏆 {} Victory! ", rank),
+                    (GameState::Victory, None) => "Assisted Victory (unranked). ".to_string(),
+                    _ => String::new(),
+                };
+                // Undo is solo-only
+                let keys = if self.game.is_solo() {
+                    "'r' to Restart, 'u' to Undo"
+                } else {
+                    "'r' to Restart"
+                };
+                let prompt = format!("{}Press {} or 'q' to Quit", result, keys);
+                match &self.notice {
+                    Some(notice) => format!("{} | {}", notice, prompt),
+                    None => prompt,
+                }
             }
-            AppState::DiscardPhase { required_damage } => {
+            AppState::Playing if self.pass_screen => format!(
+                "Press Enter when {} is ready to see their hand, or 'q' to Quit",
+                self.game.player().name
+            ),
+            AppState::Playing => match self
+                .host
+                .as_ref()
+                .and_then(|host| host.pause_notice(&self.game))
+            {
+                Some(notice) => format!("{} Press 'q' to quit", notice),
+                None => match &self.notice {
+                    Some(notice) => format!("{} | {}", notice, ui::turn_prompt(&self.view())),
+                    None => ui::turn_prompt(&self.view()),
+                },
+            },
+            AppState::Lobby => {
+                let Some(lobby) = self.host.as_ref().map(|host| host.lobby()) else {
+                    return String::new();
+                };
+                let status = if lobby.can_start() {
+                    format!("Press Enter to start with {} players", lobby.members.len())
+                } else if lobby.members.len() < 2 {
+                    "Waiting for players to join...".to_string()
+                } else {
+                    format!(
+                        "Waiting for everyone to get ready ({}/{} ready)...",
+                        lobby.ready_count(),
+                        lobby.members.len()
+                    )
+                };
                 format!(
-                    "🛡️  DEFEND: Enemy attacks! Discard cards worth {} value or more",
-                    required_damage
+                    "{} | ↑/↓ select, [ / ] change seats, 'v' spectator hands, 'c' chat rules, 'q' quit",
+                    status
                 )
             }
-            AppState::Victory => "Press 'r' to Restart or 'q' to Quit".to_string(),
-            AppState::Defeat => "Press 'r' to Restart or 'q' to Quit".to_string(),
+            AppState::ResumePrompt => {
+                "Resume your saved game? Press 'y' to resume or 'n' for a new game".to_string()
+            }
             AppState::RestartConfirmation => {
                 "Restart game? Press 'y' to confirm or 'n' to cancel".to_string()
             }
@@ -252,7 +676,276 @@ impl App {
     }
 }
 
+/// `count` bots called `name`, seeded from `seed` if one was given
+fn make_bots(name: &str, count: usize, seed: Option<u64>) -> Vec<Box<dyn Bot>> {
+    let seed = seed.unwrap_or_else(rand::random);
+    (0..count)
+        .map(|i| {
+            let seed = seed.wrapping_add(i as u64);
+            bot::by_name(name, seed, Budget::default()).expect("known bot")
+        })
+        .collect()
+}
+
+/// Start a game, seeded if a seed was given on the command line
+fn new_game(players: usize, seed: Option<u64>) -> Game {
+    match seed {
+        Some(seed) => Game::new_with_seed(players, seed),
+        None => Game::new(players),
+    }
+}
+
 fn main() -> Result<(), io::Error> {
+    let command = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            eprintln!("{}", cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Play(options) => play(options),
+        Command::Replay(path) => view_replay(&path),
+        Command::Host(options) => host_game(options),
+        Command::Join(options) => join_game(options, false),
+        Command::Watch(options) => join_game(options, true),
+        Command::Simulate(options) => {
+            simulate(options);
+            Ok(())
+        }
+        Command::Solve(options) => solve(options),
+        Command::BotServer(options) => serve_bot(options),
+    }
+}
+
+/// Play one game with a bot in another process, over stdin and stdout
+fn serve_bot(options: BotServerOptions) -> io::Result<()> {
+    let mut game = new_game(options.players, options.seed);
+    pipe::serve(&mut game, io::stdin().lock(), io::stdout().lock())
+}
+
+/// Search a seeded solo deal for a winning line and print it
+fn solve(options: SolveOptions) -> io::Result<()> {
+    let mut game = Game::new_solo_with_seed(options.seed);
+    let mut solver = Solver::new(options.nodes);
+    let verdict = solver.solve(&game);
+    let searched = format!("{} positions searched", solver.nodes());
+
+    let (jesters, line, fewest) = match verdict {
+        Verdict::Winnable {
+            jesters,
+            line,
+            fewest,
+        } => (jesters, line, fewest),
+        Verdict::Unwinnable => {
+            println!("Seed {}: unwinnable ({})", options.seed, searched);
+            return Ok(());
+        }
+        Verdict::Unknown => {
+            println!(
+                "Seed {}: unknown, no win found within {} ({})",
+                options.seed, options.nodes, searched
+            );
+            return Ok(());
+        }
+    };
+
+    let rank = match jesters {
+        0 => VictoryRank::Gold,
+        1 => VictoryRank::Silver,
+        _ => VictoryRank::Bronze,
+    };
+    println!(
+        "Seed {}: winnable with {} Jester(s) for {}{} in {} actions ({})",
+        options.seed,
+        jesters,
+        rank,
+        if fewest { "" } else { ", fewer not ruled out" },
+        line.len(),
+        searched
+    );
+    for (step, action) in line.iter().enumerate() {
+        let enemy = game
+            .current_enemy
+            .as_ref()
+            .map(|e| format!("{} {}/{}", e.card.display(), e.current_hp, e.max_hp))
+            .unwrap_or_default();
+        println!(
+            "{:>4}. [{}] {}",
+            step + 1,
+            enemy,
+            action.describe(&game.player().hand)
+        );
+        if let Err(e) = game.apply(action.clone()) {
+            eprintln!("Error: The solver's line was rejected: {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(path) = &options.save {
+        Replay::from_game(&game).save_to(path)?;
+        println!("Saved the line to {}", path.display());
+    }
+    Ok(())
+}
+
+/// Play games headless with a bot in every seat and print the results
+fn simulate(options: SimulateOptions) {
+    // The bot name was checked when parsing arguments
+    let seed = options.seed.unwrap_or_else(rand::random);
+    let mut bot = bot::by_name(&options.bot, seed, options.budget).expect("known bot");
+    let started = std::time::Instant::now();
+    let report = sim::simulate(bot.as_mut(), options.players, options.games, seed);
+
+    let share = |count: usize| {
+        if report.games == 0 {
+            0.0
+        } else {
+            100.0 * count as f64 / report.games as f64
+        }
+    };
+    println!(
+        "{} games, {} player(s), {} bot, seeds {}..{} ({:.1}s)",
+        report.games,
+        options.players,
+        bot.name(),
+        seed,
+        seed.wrapping_add(report.games as u64),
+        started.elapsed().as_secs_f64()
+    );
+    println!(
+        "Win rate:               {:>6.2}% ({}/{})",
+        100.0 * report.win_rate(),
+        report.wins,
+        report.games
+    );
+    if options.players == 1 {
+        for (rank, count) in [
+            ("Gold", report.gold),
+            ("Silver", report.silver),
+            ("Bronze", report.bronze),
+        ] {
+            println!("  {:<22}{:>6.2}% ({})", rank, share(count), count);
+        }
+    }
+    println!(
+        "Avg enemies defeated:   {:>6.2} / 12",
+        report.average_enemies_defeated()
+    );
+    if options.players == 1 {
+        println!(
+            "Avg Jesters used:       {:>6.2}",
+            report.average_jesters_used()
+        );
+    }
+    if report.stalled > 0 {
+        println!(
+            "Stalled:                {:>6} (over {} actions)",
+            report.stalled,
+            sim::MAX_ACTIONS
+        );
+    }
+    if report.rejected > 0 {
+        println!("Illegal actions:        {:>6}", report.rejected);
+    }
+}
+
+/// Host a LAN game, playing one of the seats in the terminal
+fn host_game(options: HostOptions) -> io::Result<()> {
+    let name = options.name.as_deref().unwrap_or("Host");
+    let host = match Host::bind(("0.0.0.0", options.port), name) {
+        Ok(mut host) => {
+            host.set_settings(TableSettings {
+                spectators_see_hands: options.show_hands,
+                chat_mode: if options.strict_chat {
+                    ChatMode::Strict
+                } else {
+                    ChatMode::Free
+                },
+            });
+            for bot in make_bots(&options.bot, options.bots, options.seed) {
+                host.add_bot(bot);
+            }
+            host
+        }
+        Err(e) => {
+            eprintln!("Error: Could not listen on port {}: {}", options.port, e);
+            std::process::exit(1);
+        }
+    };
+
+    let mut app = App::hosting(host, &options);
+    with_terminal(|terminal| run_app(terminal, &mut app))
+}
+
+/// Join a LAN game hosted elsewhere, or just watch it
+fn join_game(options: JoinOptions, spectate: bool) -> io::Result<()> {
+    let name = options.name.unwrap_or_default();
+    let client = match &options.token {
+        Some(token) => Client::rejoin(&options.addr, token),
+        None if spectate => Client::watch(&options.addr, &name),
+        None => Client::connect(&options.addr, &name),
+    };
+    let client = match client {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Error: Could not join {}: {}", options.addr, e);
+            std::process::exit(1);
+        }
+    };
+
+    let mut app = client_app::ClientApp::new(client, options.addr, spectate);
+    with_terminal(|terminal| client_app::run(terminal, &mut app))
+}
+
+/// Play solo or hot-seat in the terminal
+fn play(options: Options) -> io::Result<()> {
+    // Create app, restoring the saved game first if asked to
+    let mut app = App::new(&options);
+    if options.resume {
+        match save::load_game() {
+            Ok(Some(game)) => app.resume(game),
+            Ok(None) => {
+                eprintln!("Error: No saved game to resume");
+                std::process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Error: Could not load saved game: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else if save::has_saved_game() {
+        // Offer the save even for a seeded deal, which would overwrite it on quit
+        app.state = AppState::ResumePrompt;
+        app.resume_offered = true;
+    }
+
+    with_terminal(|terminal| run_app(terminal, &mut app))
+}
+
+/// Step through a recorded game in the terminal
+fn view_replay(path: &std::path::Path) -> io::Result<()> {
+    let viewer = Replay::load_from(path)
+        .map_err(|e| e.to_string())
+        .and_then(|replay| ReplayViewer::new(replay).map_err(|e| e.to_string()));
+    let mut viewer = match viewer {
+        Ok(viewer) => viewer,
+        Err(e) => {
+            eprintln!("Error: Could not load replay {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    with_terminal(|terminal| replay_viewer::run(terminal, &mut viewer))
+}
+
+/// Run a UI loop in the alternate screen, restoring the terminal afterwards
+fn with_terminal<F>(run: F) -> io::Result<()>
+where
+    F: FnOnce(&mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()>,
+{
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -260,11 +953,8 @@ fn main() -> Result<(), io::Error> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app
-    let mut app = App::new();
-
     // Main loop
-    let res = run_app(&mut terminal, &mut app);
+    let res = run(&mut terminal);
 
     // Restore terminal
     disable_raw_mode()?;
@@ -294,21 +984,71 @@ fn run_app<B: ratatui::backend::Backend>(
             }
 
             let action_prompt = app.get_action_prompt();
+            if let (AppState::Lobby, Some(host)) = (&app.state, &app.host) {
+                let port = host
+                    .local_addr()
+                    .map(|addr| addr.port())
+                    .unwrap_or_default();
+                let address = net::local_ip()
+                    .map(|ip| format!("{}:{}", ip, port))
+                    .unwrap_or_else(|| format!("<this machine's IP>:{}", port));
+                let heading = format!("Players join with: kingslayer join {}", address);
+                ui::render_lobby(
+                    f,
+                    &host.lobby(),
+                    &heading,
+                    Some(app.lobby_cursor),
+                    &action_prompt,
+                );
+                return;
+            }
+            if app.pass_screen {
+                ui::render_pass_screen(f, &app.game.player().name, &action_prompt);
+                return;
+            }
+
+            let view = app.view();
             ui::render_game(
                 f,
-                &app.game,
-                &app.selected_cards,
+                &view,
+                ui::HandMarks {
+                    selected: &app.selected_cards,
+                    errors: &app.error_cards,
+                    hint: app.hint.as_ref(),
+                },
                 app.log_scroll_offset,
-                app.guide_scroll_offset,
+                app.info_pane(),
                 &action_prompt,
             );
+            if view.phase == Phase::ChooseNextPlayer && view.is_my_turn() && !view.is_over() {
+                ui::render_player_choice(f, &view, app.player_cursor);
+            }
         })?;
 
+        // Redraw at least every 100ms so network updates and the clock show up
+        app.poll_network();
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+
         if let Event::Key(key) = event::read()? {
             if key.kind != KeyEventKind::Press {
                 continue;
             }
 
+            // A chat message being written takes every key
+            if let Some(chat_box) = &mut app.chat_box {
+                match chat_box.handle_key(key.code) {
+                    ChatInput::Editing => {}
+                    ChatInput::Send(text) => app.send_chat(&text),
+                    ChatInput::Cancel => {
+                        app.chat_box = None;
+                        app.chat_error = None;
+                    }
+                }
+                continue;
+            }
+
             // Global keys
             match key.code {
                 KeyCode::Char('q') => {
@@ -327,6 +1067,10 @@ fn run_app<B: ratatui::backend::Backend>(
                 KeyCode::Up => {
                     if app.show_help {
                         app.scroll_help_up();
+                    } else if matches!(app.state, AppState::Lobby) {
+                        app.move_lobby_cursor(true);
+                    } else if app.game.phase == Phase::ChooseNextPlayer {
+                        app.move_player_cursor_up();
                     } else {
                         app.scroll_log_up();
                     }
@@ -336,6 +1080,10 @@ fn run_app<B: ratatui::backend::Backend>(
                     if app.show_help {
                         let help_line_count = ui::get_help_line_count();
                         app.scroll_help_down(help_line_count);
+                    } else if matches!(app.state, AppState::Lobby) {
+                        app.move_lobby_cursor(false);
+                    } else if app.game.phase == Phase::ChooseNextPlayer {
+                        app.move_player_cursor_down();
                     } else {
                         app.scroll_log_down();
                     }
@@ -363,29 +1111,40 @@ fn run_app<B: ratatui::backend::Backend>(
             }
 
             match &app.state {
-                AppState::Playing => match key.code {
+                AppState::Playing if app.pass_screen => {
+                    if key.code == KeyCode::Enter {
+                        app.pass_screen = false;
+                    }
+                }
+                AppState::Playing if app.game.is_over() => match key.code {
+                    KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        app.redo();
+                    }
+                    KeyCode::Char('r') => app.restart_game(),
+                    KeyCode::Char('u') => app.undo(),
+                    KeyCode::Char('c') => app.open_chat(),
+                    _ => {}
+                },
+                AppState::Playing if app.game.phase == Phase::ChooseNextPlayer => match key.code {
                     KeyCode::Char(c) if c.is_ascii_digit() => {
                         let digit = c.to_digit(10).unwrap() as usize;
-                        // Convert 1-8 to indices 0-7 (1-based numbering for user)
-                        if (1..=8).contains(&digit) {
-                            app.toggle_card_selection(digit - 1);
+                        if digit >= 1 {
+                            app.apply(GameAction::ChooseNextPlayer(digit - 1));
                         }
                     }
                     KeyCode::Enter => {
-                        app.play_selected_cards();
+                        app.apply(GameAction::ChooseNextPlayer(app.player_cursor));
                     }
-                    KeyCode::Char(' ') => {
-                        app.yield_turn();
-                    }
-                    KeyCode::Char('j') => {
-                        app.use_jester();
+                    KeyCode::Char('s') => {
+                        app.save_game();
                     }
                     KeyCode::Char('r') => {
                         app.state = AppState::RestartConfirmation;
                     }
+                    KeyCode::Char('c') => app.open_chat(),
                     _ => {}
                 },
-                AppState::DiscardPhase { required_damage } => match key.code {
+                AppState::Playing => match key.code {
                     KeyCode::Char(c) if c.is_ascii_digit() => {
                         let digit = c.to_digit(10).unwrap() as usize;
                         // Convert 1-8 to indices 0-7 (1-based numbering for user)
@@ -393,23 +1152,62 @@ fn run_app<B: ratatui::backend::Backend>(
                             app.toggle_card_selection(digit - 1);
                         }
                     }
-                    KeyCode::Enter => {
-                        app.discard_selected_cards(*required_damage);
+                    KeyCode::Enter => match app.game.phase {
+                        Phase::Play => app.play_selected_cards(),
+                        Phase::Discard { .. } => app.discard_selected_cards(),
+                        Phase::ChooseNextPlayer => {}
+                    },
+                    KeyCode::Char(' ') => {
+                        app.apply(GameAction::Yield);
                     }
+                    KeyCode::Char('a') => app.select_suggested_discard(),
+                    KeyCode::Char('?') => app.show_hint(),
                     KeyCode::Char('j') => {
-                        // Solo mode: Use Jester power during discard phase (Step 4)
-                        app.use_jester();
+                        // Solo mode: Jester power works at Step 1 and during the discard phase (Step 4)
+                        app.apply(GameAction::UseSoloJester);
+                    }
+                    KeyCode::Char('s') => {
+                        app.save_game();
+                    }
+                    KeyCode::Char('u') => {
+                        app.undo();
+                    }
+                    KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        app.redo();
                     }
                     KeyCode::Char('r') => {
                         app.state = AppState::RestartConfirmation;
                     }
+                    KeyCode::Char('c') => app.open_chat(),
                     _ => {}
                 },
-                AppState::Victory | AppState::Defeat => {
-                    if key.code == KeyCode::Char('r') {
-                        app.restart_game();
+                AppState::Lobby => match key.code {
+                    KeyCode::Enter => app.start_lan_game(),
+                    KeyCode::Char('[') => app.move_lobby_member(true),
+                    KeyCode::Char(']') => app.move_lobby_member(false),
+                    KeyCode::Char('v') => app.toggle_spectator_hands(),
+                    KeyCode::Char('c') => app.toggle_chat_mode(),
+                    _ => {}
+                },
+                AppState::ResumePrompt => match key.code {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => {
+                        app.resume_offered = false;
+                        match save::load_game() {
+                            Ok(Some(game)) => app.resume(game),
+                            Ok(None) => app.state = AppState::Playing,
+                            Err(e) => {
+                                app.game
+                                    .log(format!("Error: Could not load saved game: {}", e));
+                                app.state = AppState::Playing;
+                            }
+                        }
                     }
-                }
+                    KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                        app.resume_offered = false;
+                        app.state = AppState::Playing;
+                    }
+                    _ => {}
+                },
                 AppState::RestartConfirmation => match key.code {
                     KeyCode::Char('y') | KeyCode::Char('Y') => {
                         app.restart_game();
@@ -422,11 +1220,18 @@ fn run_app<B: ratatui::backend::Backend>(
                 },
                 AppState::QuitConfirmation => match key.code {
                     KeyCode::Char('y') | KeyCode::Char('Y') => {
-                        return Ok(()); // Actually quit the game
+                        return app.save_on_quit(); // Actually quit the game
                     }
                     KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                        // Return to Playing state
-                        app.state = AppState::Playing;
+                        // Return to Playing state, or to the resume prompt or the lobby
+                        // if the game hasn't started
+                        app.state = if app.resume_offered {
+                            AppState::ResumePrompt
+                        } else if app.host.as_ref().is_some_and(|host| !host.is_started()) {
+                            AppState::Lobby
+                        } else {
+                            AppState::Playing
+                        };
                     }
                     _ => {}
                 },
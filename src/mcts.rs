@@ -0,0 +1,276 @@
+use crate::action::GameAction;
+use crate::bot::{Bot, GreedyBot, HeuristicBot};
+use crate::game::{Game, GameRng, GameState};
+use crate::view::GameView;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::time::{Duration, Instant};
+
+/// Exploration constant for UCB1
+const EXPLORATION: f64 = 0.7;
+
+/// Actions a rollout may take before it is scored as it stands
+const ROLLOUT_LIMIT: usize = 300;
+
+/// How long a search may think for each decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    pub iterations: usize,      // Playouts per decision
+    pub time: Option<Duration>, // Stop early once this much time has passed
+}
+
+impl Default for Budget {
+    fn default() -> Self {
+        Self {
+            iterations: 500,
+            time: None,
+        }
+    }
+}
+
+/// Determinized Monte Carlo tree search. Each playout deals the cards the
+/// current seat can't see at random (`Game::determinized_for`), walks a
+/// tree shared by all deals choosing actions by UCB1, then finishes the game
+/// with the greedy bot. The most visited first action is played.
+pub struct MctsBot {
+    budget: Budget,
+    rng: GameRng,
+}
+
+/// A decision point in the search tree, reached by `action`
+struct Node {
+    action: Option<GameAction>,
+    children: Vec<usize>, // Indices into the tree
+    visits: u32,
+    available: u32, // Playouts in which `action` was legal at the parent
+    reward: f64,    // Sum over visits
+}
+
+impl Node {
+    fn new(action: Option<GameAction>) -> Self {
+        Self {
+            action,
+            children: Vec::new(),
+            visits: 0,
+            available: 0,
+            reward: 0.0,
+        }
+    }
+
+    fn ucb(&self) -> f64 {
+        let mean = self.reward / self.visits as f64;
+        mean + EXPLORATION * ((self.available as f64).ln() / self.visits as f64).sqrt()
+    }
+}
+
+impl MctsBot {
+    pub fn new(seed: u64, budget: Budget) -> Self {
+        Self {
+            budget,
+            rng: GameRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Run one playout from a fresh deal of the hidden cards, growing the tree
+    fn playout(&mut self, tree: &mut Vec<Node>, base: &Game) {
+        let mut game = base.determinized_for(base.current_player, &mut self.rng);
+        let mut path = vec![0];
+        let mut node = 0;
+
+        // Walk down the tree while every legal action has been tried
+        while !game.is_over() {
+            let actions = game.candidate_actions(true);
+            let mut legal = Vec::new();
+            let mut untried = Vec::new();
+            for action in actions {
+                let child = tree[node]
+                    .children
+                    .iter()
+                    .copied()
+                    .find(|&c| tree[c].action.as_ref() == Some(&action));
+                match child {
+                    Some(child) => {
+                        tree[child].available += 1;
+                        legal.push(child);
+                    }
+                    None => untried.push(action),
+                }
+            }
+
+            let (child, action) = if let Some(action) = untried.choose(&mut self.rng) {
+                let mut child = Node::new(Some(action.clone()));
+                child.available = 1;
+                let index = tree.len();
+                tree.push(child);
+                tree[node].children.push(index);
+                (index, action.clone())
+            } else {
+                let best = legal
+                    .into_iter()
+                    .max_by(|&a, &b| tree[a].ucb().total_cmp(&tree[b].ucb()));
+                let Some(child) = best else {
+                    break;
+                };
+                (child, tree[child].action.clone().unwrap())
+            };
+
+            if game.apply(action).is_err() {
+                break;
+            }
+            path.push(child);
+            node = child;
+            if tree[child].visits == 0 {
+                break;
+            }
+        }
+
+        let reward = rollout(&mut game);
+        for &i in &path {
+            tree[i].visits += 1;
+            tree[i].reward += reward;
+        }
+    }
+
+    /// Search from `game` for the current player's best action
+    pub fn search(&mut self, game: &Game) -> GameAction {
+        let actions = game.candidate_actions(true);
+        if actions.len() <= 1 {
+            return actions.into_iter().next().unwrap_or(GameAction::Yield);
+        }
+
+        // Strip the history once, so each playout clones less
+        let base = game.determinized_for(game.current_player, &mut self.rng);
+        let started = Instant::now();
+        let mut tree = vec![Node::new(None)];
+        for _ in 0..self.budget.iterations {
+            if self
+                .budget
+                .time
+                .is_some_and(|time| started.elapsed() >= time)
+            {
+                break;
+            }
+            self.playout(&mut tree, &base);
+        }
+
+        tree[0]
+            .children
+            .iter()
+            .max_by_key(|&&c| tree[c].visits)
+            .and_then(|&c| tree[c].action.clone())
+            // No playout finished in the budget, so any legal action will do
+            .unwrap_or_else(|| actions[0].clone())
+    }
+}
+
+impl Bot for MctsBot {
+    fn name(&self) -> &'static str {
+        "mcts"
+    }
+
+    /// A view alone has too little to deal from, so this plays like the heuristic bot
+    fn choose(&mut self, view: &GameView) -> GameAction {
+        HeuristicBot.choose(view)
+    }
+
+    fn choose_from_game(&mut self, game: &Game) -> GameAction {
+        self.search(game)
+    }
+}
+
+/// Finish the game with the greedy bot and score how it went, from 0 to 1
+fn rollout(game: &mut Game) -> f64 {
+    let mut bot = GreedyBot;
+    for _ in 0..ROLLOUT_LIMIT {
+        if game.is_over() {
+            break;
+        }
+        let view = GameView::for_seat(game, game.current_player);
+        if game.apply(bot.choose(&view)).is_err() {
+            break;
+        }
+    }
+    score(game)
+}
+
+/// Wins beat everything, and fewer Jesters beat more; otherwise count the
+/// enemies defeated and the damage on the current one
+fn score(game: &Game) -> f64 {
+    if matches!(game.game_state, GameState::Victory) {
+        return 1.0 - 0.1 * game.jesters_used as f64;
+    }
+    let defeated = 12 - game.castle_deck.len() - game.current_enemy.is_some() as usize;
+    let wounded = game
+        .current_enemy
+        .as_ref()
+        .map_or(0.0, |e| 1.0 - e.current_hp as f64 / e.max_hp as f64);
+    0.7 * (defeated as f64 + wounded) / 12.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Card, Rank, Suit};
+    use crate::enemy::Enemy;
+    use crate::game::Phase;
+
+    #[test]
+    fn test_search_takes_an_exact_kill_on_the_last_king() {
+        let mut game = Game::new_solo_with_seed(12);
+        game.castle_deck.cards.clear();
+        game.current_enemy = Some(Enemy::new(Card::new(Suit::Spades, Rank::King)));
+        game.current_enemy.as_mut().unwrap().current_hp = 6;
+        game.players[0].hand = vec![
+            Card::new(Suit::Hearts, Rank::Two),
+            Card::new(Suit::Diamonds, Rank::Six),
+            Card::new(Suit::Hearts, Rank::Three),
+        ];
+
+        let budget = Budget {
+            iterations: 100,
+            time: None,
+        };
+        let mut bot = MctsBot::new(1, budget);
+        assert_eq!(bot.choose_from_game(&game), GameAction::Play(vec![1]));
+    }
+
+    #[test]
+    fn test_search_is_reproducible_and_only_makes_legal_moves() {
+        let budget = Budget {
+            iterations: 10,
+            time: None,
+        };
+        for seed in 0..3 {
+            let mut game = Game::new_with_seed(1 + seed as usize, seed);
+            let mut bot = MctsBot::new(seed, budget);
+            let mut again = MctsBot::new(seed, budget);
+            while !game.is_over() && game.actions.len() < 40 {
+                let action = bot.choose_from_game(&game);
+                assert_eq!(again.choose_from_game(&game), action);
+                game.apply(action).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_with_no_time_to_think_still_plays_legally() {
+        let mut game = Game::new_solo_with_seed(3);
+        game.players[0].hand = vec![
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Clubs, Rank::Three),
+            Card::new(Suit::Spades, Rank::Four),
+        ];
+        // The Jack attacks for 10, less 3 from Spades
+        game.shield_value = 3;
+        game.phase = Phase::Discard { required: 7 };
+
+        let budget = Budget {
+            iterations: 1,
+            time: Some(Duration::ZERO),
+        };
+        let mut bot = MctsBot::new(1, budget);
+        let action = bot.choose_from_game(&game);
+        assert!(matches!(action, GameAction::Discard(_)));
+        game.apply(action).unwrap();
+    }
+}
@@ -0,0 +1,1362 @@
+use crate::action::GameAction;
+use crate::bot::{self, Bot};
+use crate::chat::{self, ChatMessage, ChatMode};
+use crate::error::{ChatError, RuleError};
+use crate::game::Game;
+use crate::view::GameView;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{IpAddr, Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs, UdpSocket};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Port used when `host` is not given one
+pub const DEFAULT_PORT: u16 = 7878;
+
+/// How long `Client::connect` waits for the host to let it in
+const JOIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often each side tells the other it is still there
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long a connection may stay silent before it is treated as dropped
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Largest table a lobby can seat
+const MAX_PLAYERS: usize = 4;
+
+/// A message from a client to the host, sent as one line of JSON
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Ask for a place in the lobby
+    Join { name: String },
+    /// Take back a seat after the connection dropped, using the token from `Welcome`
+    Rejoin { token: String },
+    /// Watch the game without taking a seat
+    Watch { name: String },
+    /// Tell the host whether this player is ready to start
+    Ready { ready: bool },
+    /// Take a turn
+    Action { action: GameAction },
+    /// Say something at the table
+    Chat { text: String },
+    /// Nothing to say, but still connected
+    Ping,
+}
+
+/// A message from the host to a client, sent as one line of JSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// The join was accepted, under this name. `token` takes the seat back
+    /// if the connection drops.
+    Welcome {
+        name: String,
+        token: String,
+        #[serde(default)]
+        settings: TableSettings,
+    },
+    /// Who is waiting to play, sent whenever the lobby changes
+    Lobby { lobby: Lobby },
+    /// The game as this seat may see it, sent after every change
+    State { view: GameView },
+    /// The client's last action broke a rule
+    Rejected { error: RuleError },
+    /// The host refused a message or the connection
+    Error { message: String },
+    /// Seats whose players dropped; the game is paused until they are empty
+    Waiting { seats: Vec<usize> },
+    /// Someone said something at the table
+    Chat { message: ChatMessage },
+    /// The client's last chat message broke the communication rules
+    ChatRejected { error: ChatError },
+    /// Nothing to say, but still connected
+    Ping,
+}
+
+/// One person waiting in the lobby
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LobbyMember {
+    pub name: String,
+    pub ready: bool,
+    pub is_host: bool, // The host is always ready: starting the game is up to them
+}
+
+/// Table options the host picks in the lobby
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableSettings {
+    pub spectators_see_hands: bool,
+    #[serde(default)]
+    pub chat_mode: ChatMode,
+}
+
+/// The table before a game starts, listed in seat order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lobby {
+    pub members: Vec<LobbyMember>,
+    pub you: Option<usize>, // Position of the member this lobby was sent to; None for spectators
+    pub spectators: usize,
+    pub settings: TableSettings,
+}
+
+impl Lobby {
+    /// Check if the game can start: 2-4 players, all of them ready
+    pub fn can_start(&self) -> bool {
+        (2..=MAX_PLAYERS).contains(&self.members.len())
+            && self.members.iter().all(|member| member.ready)
+    }
+
+    /// Number of members who are ready
+    pub fn ready_count(&self) -> usize {
+        self.members.iter().filter(|member| member.ready).count()
+    }
+}
+
+/// Prompt shown while the game is paused for dropped players
+pub fn reconnect_notice(names: &[&str]) -> String {
+    format!("⏸ Waiting for {} to reconnect…", names.join(" and "))
+}
+
+/// Best guess at this machine's address on the local network, to tell other players
+pub fn local_ip() -> Option<IpAddr> {
+    // Connecting a UDP socket sends nothing, but picks the outgoing interface
+    let socket = UdpSocket::bind(("0.0.0.0", 0)).ok()?;
+    socket.connect(("192.168.0.1", 9)).ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}
+
+/// Write one message as a line of JSON
+pub fn write_message<W: Write, T: Serialize>(writer: &mut W, message: &T) -> io::Result<()> {
+    let mut line = serde_json::to_string(message)?;
+    line.push('\n');
+    writer.write_all(line.as_bytes())?;
+    writer.flush()
+}
+
+/// Read the next line of JSON, skipping blank lines, or `None` at the end of the stream
+pub fn read_message<R: BufRead, T: DeserializeOwned>(reader: &mut R) -> io::Result<Option<T>> {
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        if !line.trim().is_empty() {
+            return Ok(Some(serde_json::from_str(&line)?));
+        }
+    }
+}
+
+/// What a connection's reader thread reports to the host
+enum Incoming {
+    Message(ClientMessage),
+    Malformed(String),
+    Closed,
+}
+
+/// A connected client: in the lobby once it has sent `Join`, seated once the
+/// game starts, or a spectator once it has sent `Watch`
+struct Connection {
+    id: usize,
+    joined: bool,
+    spectator: bool,
+    ready: bool,
+    seat: Option<usize>,
+    name: String,
+    token: String, // Session token for taking the seat back after a drop
+    writer: TcpStream,
+}
+
+/// A seat whose player dropped, held for them to rejoin
+struct Vacancy {
+    seat: usize,
+    name: String,
+    token: String,
+}
+
+/// A computer player filling a place at the table, seated like a connection
+struct HostedBot {
+    name: String,
+    seat: Option<usize>,
+    bot: Box<dyn Bot>,
+}
+
+/// A place in the lobby's seat order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Member {
+    Host,
+    Client(usize), // Connection id
+    Bot(usize),    // Index into `Host::bots`
+}
+
+/// Serves an authoritative `Game` to clients over TCP. Clients gather in a
+/// lobby first; the host picks the seat order and starts the game once
+/// everyone is ready. Bots may fill empty places, and take their turns as
+/// the host polls. Spectators may watch at any time.
+pub struct Host {
+    listener: TcpListener,
+    name: String,
+    settings: TableSettings,
+    connections: Vec<Connection>,
+    bots: Vec<HostedBot>,
+    order: Vec<Member>, // Lobby seat order, host included
+    host_seat: usize,
+    vacancies: Vec<Vacancy>,
+    last_heartbeat: Instant,
+    next_id: usize,
+    incoming_tx: Sender<(usize, Incoming)>,
+    incoming: Receiver<(usize, Incoming)>,
+    started: bool,
+    chat: Vec<ChatMessage>,
+}
+
+impl Host {
+    /// Listen for clients on `addr`, with the host playing as `name`
+    pub fn bind<A: ToSocketAddrs>(addr: A, name: &str) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let (incoming_tx, incoming) = mpsc::channel();
+        Ok(Self {
+            listener,
+            name: name.to_string(),
+            settings: TableSettings::default(),
+            connections: Vec::new(),
+            bots: Vec::new(),
+            order: vec![Member::Host],
+            host_seat: 0,
+            vacancies: Vec::new(),
+            last_heartbeat: Instant::now(),
+            next_id: 0,
+            incoming_tx,
+            incoming,
+            started: false,
+            chat: Vec::new(),
+        })
+    }
+
+    /// Address the host is listening on
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Seats currently taken by clients
+    pub fn seated(&self) -> Vec<usize> {
+        let mut seats: Vec<usize> = self.connections.iter().filter_map(|c| c.seat).collect();
+        seats.sort_unstable();
+        seats
+    }
+
+    /// Check if `start` has been called
+    pub fn is_started(&self) -> bool {
+        self.started
+    }
+
+    /// Seats whose players dropped and have not rejoined yet, in seat order
+    pub fn waiting_for(&self) -> Vec<usize> {
+        let mut seats: Vec<usize> = self.vacancies.iter().map(|v| v.seat).collect();
+        seats.sort_unstable();
+        seats
+    }
+
+    /// Check if play is paused until dropped players rejoin
+    pub fn is_paused(&self) -> bool {
+        !self.vacancies.is_empty()
+    }
+
+    /// The pause prompt naming the players who dropped, if any did
+    pub fn pause_notice(&self, game: &Game) -> Option<String> {
+        let seats = self.waiting_for();
+        let names: Vec<&str> = seats
+            .iter()
+            .map(|&seat| game.players[seat].name.as_str())
+            .collect();
+        (!names.is_empty()).then(|| reconnect_notice(&names))
+    }
+
+    /// The lobby as the host sees it
+    pub fn lobby(&self) -> Lobby {
+        self.lobby_for(Member::Host)
+    }
+
+    /// Table options currently in force
+    pub fn settings(&self) -> TableSettings {
+        self.settings
+    }
+
+    /// Change the table options; only possible before the game starts
+    pub fn set_settings(&mut self, settings: TableSettings) {
+        if !self.started {
+            self.settings = settings;
+            self.send_lobby();
+        }
+    }
+
+    /// Everything said at the table so far, oldest first
+    pub fn chat(&self) -> &[ChatMessage] {
+        &self.chat
+    }
+
+    /// Say something at the table as the host
+    pub fn say(&mut self, text: &str) -> Result<(), ChatError> {
+        let text = chat::check_message(self.settings.chat_mode, self.table_size(), text)?;
+        let from = self.name.clone();
+        self.send_chat(ChatMessage { from, text });
+        Ok(())
+    }
+
+    /// Number of spectators watching
+    pub fn spectators(&self) -> usize {
+        self.connections.iter().filter(|c| c.spectator).count()
+    }
+
+    /// Number of players in the lobby, host included
+    pub fn table_size(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Give `bot` a place in the lobby, always ready. Returns false if the
+    /// lobby is full or the game has started.
+    pub fn add_bot(&mut self, bot: Box<dyn Bot>) -> bool {
+        if self.started || self.order.len() >= MAX_PLAYERS {
+            return false;
+        }
+        let name = format!("Bot {} ({})", self.bots.len() + 1, bot.name());
+        self.order.push(Member::Bot(self.bots.len()));
+        self.bots.push(HostedBot {
+            name,
+            seat: None,
+            bot,
+        });
+        self.send_lobby();
+        true
+    }
+
+    /// Move the lobby member at position `from` to position `to`
+    pub fn move_member(&mut self, from: usize, to: usize) {
+        if self.started || from >= self.order.len() || to >= self.order.len() {
+            return;
+        }
+        let member = self.order.remove(from);
+        self.order.insert(to, member);
+        self.send_lobby();
+    }
+
+    /// Seat the lobby in order at `game`, which must have one seat per member,
+    /// and begin sending it state. Returns the host's own seat.
+    pub fn start(&mut self, game: &mut Game) -> usize {
+        assert_eq!(game.players.len(), self.order.len());
+        for seat in 0..self.order.len() {
+            match self.order[seat] {
+                Member::Host => self.host_seat = seat,
+                Member::Bot(index) => self.bots[index].seat = Some(seat),
+                Member::Client(id) => {
+                    if let Some(connection) = self.connections.iter_mut().find(|c| c.id == id) {
+                        connection.seat = Some(seat);
+                    }
+                }
+            }
+        }
+        self.started = true;
+        self.name_seats(game);
+        self.broadcast(game);
+        self.host_seat
+    }
+
+    /// Put everyone's names on their seats, e.g. after dealing a new game
+    pub fn name_seats(&self, game: &mut Game) {
+        game.players[self.host_seat].name = self.name.clone();
+        for connection in &self.connections {
+            if let Some(seat) = connection.seat {
+                game.players[seat].name = connection.name.clone();
+            }
+        }
+        for hosted in &self.bots {
+            if let Some(seat) = hosted.seat {
+                game.players[seat].name = hosted.name.clone();
+            }
+        }
+    }
+
+    /// Accept new clients and apply their messages to `game` without blocking.
+    /// Returns true if the game changed (and was sent to the clients).
+    pub fn poll(&mut self, game: &mut Game) -> bool {
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => self.add_connection(stream),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(_) => break,
+            }
+        }
+
+        let mut changed = false;
+        while let Ok((id, incoming)) = self.incoming.try_recv() {
+            changed |= self.handle(id, incoming, game);
+        }
+        if self.started && !self.is_paused() {
+            changed |= self.take_bot_turns(game);
+        }
+
+        if self.last_heartbeat.elapsed() >= HEARTBEAT_INTERVAL {
+            self.last_heartbeat = Instant::now();
+            for i in 0..self.connections.len() {
+                self.send(i, &ServerMessage::Ping);
+            }
+        }
+
+        if changed && self.started {
+            self.broadcast(game);
+        }
+        changed
+    }
+
+    /// Let the bots play while it is one of their turns
+    fn take_bot_turns(&mut self, game: &mut Game) -> bool {
+        let mut seated: Vec<(usize, &mut dyn Bot)> = self
+            .bots
+            .iter_mut()
+            .filter_map(|hosted| {
+                hosted
+                    .seat
+                    .map(|seat| (seat, &mut *hosted.bot as &mut dyn Bot))
+            })
+            .collect();
+        bot::take_turns(game, &mut seated)
+    }
+
+    /// Send every seated client its own view of the game, and spectators theirs
+    pub fn broadcast(&mut self, game: &Game) {
+        if !self.started {
+            return;
+        }
+        for i in 0..self.connections.len() {
+            if let Some(view) = self.view_for(i, game) {
+                self.send(i, &ServerMessage::State { view });
+            }
+        }
+    }
+
+    /// What connection `index` may see of the game, if it is seated or watching
+    fn view_for(&self, index: usize, game: &Game) -> Option<GameView> {
+        let connection = &self.connections[index];
+        if connection.spectator {
+            let show_hands = self.settings.spectators_see_hands;
+            return Some(GameView::for_spectator(game, show_hands));
+        }
+        connection.seat.map(|seat| GameView::for_seat(game, seat))
+    }
+
+    fn add_connection(&mut self, stream: TcpStream) {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let reader = match stream
+            .set_nonblocking(false)
+            .and_then(|_| stream.set_read_timeout(Some(CONNECTION_TIMEOUT)))
+            // A client that stops reading mustn't stall the table on a full send buffer
+            .and_then(|_| stream.set_write_timeout(Some(CONNECTION_TIMEOUT)))
+            .and_then(|_| stream.try_clone())
+        {
+            Ok(reader) => reader,
+            Err(_) => return,
+        };
+        let tx = self.incoming_tx.clone();
+        thread::spawn(move || {
+            let mut reader = BufReader::new(reader);
+            loop {
+                let incoming = match read_message(&mut reader) {
+                    Ok(Some(message)) => Incoming::Message(message),
+                    Ok(None) => Incoming::Closed,
+                    Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                        Incoming::Malformed(e.to_string())
+                    }
+                    Err(_) => Incoming::Closed,
+                };
+                let closed = matches!(incoming, Incoming::Closed);
+                if tx.send((id, incoming)).is_err() || closed {
+                    break;
+                }
+            }
+        });
+
+        self.connections.push(Connection {
+            id,
+            joined: false,
+            spectator: false,
+            ready: false,
+            seat: None,
+            name: String::new(),
+            token: format!("{:016x}", rand::random::<u64>()),
+            writer: stream,
+        });
+    }
+
+    /// Handle one message from a connection, returning true if the game changed
+    fn handle(&mut self, id: usize, incoming: Incoming, game: &mut Game) -> bool {
+        let Some(index) = self.connections.iter().position(|c| c.id == id) else {
+            return false;
+        };
+
+        match incoming {
+            Incoming::Message(ClientMessage::Join { name }) => {
+                self.join(index, name);
+                false
+            }
+            Incoming::Message(ClientMessage::Rejoin { token }) => self.rejoin(index, token, game),
+            Incoming::Message(ClientMessage::Watch { name }) => self.watch(index, name, game),
+            Incoming::Message(ClientMessage::Ping) => false,
+            Incoming::Message(ClientMessage::Chat { text }) => {
+                self.chat_from(index, &text);
+                false
+            }
+            Incoming::Message(ClientMessage::Ready { ready }) => {
+                if self.started || !self.connections[index].joined {
+                    let message = "Only players in the lobby can get ready".to_string();
+                    self.send(index, &ServerMessage::Error { message });
+                } else {
+                    self.connections[index].ready = ready;
+                    self.send_lobby();
+                }
+                false
+            }
+            Incoming::Message(ClientMessage::Action { action }) => {
+                let Some(seat) = self.connections[index].seat else {
+                    let message = if self.connections[index].spectator {
+                        "Spectators can't take actions".to_string()
+                    } else {
+                        "Join the game before taking actions".to_string()
+                    };
+                    self.send(index, &ServerMessage::Error { message });
+                    return false;
+                };
+                if !self.started {
+                    let message = "The game has not started yet".to_string();
+                    self.send(index, &ServerMessage::Error { message });
+                    return false;
+                }
+                if let Some(message) = self.pause_notice(game) {
+                    self.send(index, &ServerMessage::Error { message });
+                    return false;
+                }
+                match game.apply_as(seat, action) {
+                    Ok(_) => true,
+                    Err(error) => {
+                        self.send(index, &ServerMessage::Rejected { error });
+                        false
+                    }
+                }
+            }
+            Incoming::Malformed(message) => {
+                self.send(index, &ServerMessage::Error { message });
+                false
+            }
+            Incoming::Closed => {
+                let connection = self.connections.remove(index);
+                let _ = connection.writer.shutdown(Shutdown::Both);
+                if let Some(seat) = connection.seat {
+                    game.log(format!(
+                        "{} (Player {}) disconnected",
+                        connection.name,
+                        seat + 1
+                    ));
+                    self.vacancies.push(Vacancy {
+                        seat,
+                        name: connection.name,
+                        token: connection.token,
+                    });
+                    self.send_waiting();
+                    return true;
+                }
+                if connection.spectator && self.started {
+                    game.log(format!("{} stopped watching", connection.name));
+                    return true;
+                }
+                if connection.joined && !self.started {
+                    self.order.retain(|&member| member != Member::Client(id));
+                }
+                if !self.started {
+                    self.send_lobby();
+                }
+                false
+            }
+        }
+    }
+
+    /// Let a joining client into the lobby, or turn it away if the lobby is
+    /// full or the game has started
+    fn join(&mut self, index: usize, name: String) {
+        if self.connections[index].joined {
+            let message = "Already joined".to_string();
+            self.send(index, &ServerMessage::Error { message });
+            return;
+        }
+
+        if self.started || self.order.len() >= MAX_PLAYERS {
+            let message = if self.started {
+                "The game has already started"
+            } else {
+                "The lobby is full"
+            };
+            self.refuse(index, message);
+            return;
+        }
+
+        let name = match name.trim() {
+            "" => format!("Player {}", self.order.len() + 1),
+            name => name.to_string(),
+        };
+        let connection = &mut self.connections[index];
+        connection.joined = true;
+        connection.name = name.clone();
+        let token = connection.token.clone();
+        let id = connection.id;
+        let settings = self.settings;
+        self.send(
+            index,
+            &ServerMessage::Welcome {
+                name,
+                token,
+                settings,
+            },
+        );
+        self.order.push(Member::Client(id));
+        self.send_lobby();
+    }
+
+    /// Let a client watch without a seat, sending it the game if it is under way
+    fn watch(&mut self, index: usize, name: String, game: &mut Game) -> bool {
+        if self.connections[index].joined || self.connections[index].spectator {
+            let message = "Already joined".to_string();
+            self.send(index, &ServerMessage::Error { message });
+            return false;
+        }
+
+        let name = match name.trim() {
+            "" => format!("Spectator {}", self.spectators() + 1),
+            name => name.to_string(),
+        };
+        let connection = &mut self.connections[index];
+        connection.spectator = true;
+        connection.name = name.clone();
+        let token = connection.token.clone();
+        self.send(
+            index,
+            &ServerMessage::Welcome {
+                name: name.clone(),
+                token,
+                settings: self.settings,
+            },
+        );
+
+        if !self.started {
+            self.send_lobby();
+            return false;
+        }
+        self.send_chat_history(index);
+        game.log(format!("{} is watching", name));
+        true
+    }
+
+    /// Give a dropped player their seat back and send them the game as it stands
+    fn rejoin(&mut self, index: usize, token: String, game: &mut Game) -> bool {
+        let Some(position) = self.vacancies.iter().position(|v| v.token == token) else {
+            self.refuse(index, "There is no seat waiting for that session");
+            return false;
+        };
+        if self.connections[index].joined {
+            let message = "Already joined".to_string();
+            self.send(index, &ServerMessage::Error { message });
+            return false;
+        }
+
+        let vacancy = self.vacancies.remove(position);
+        game.log(format!(
+            "{} (Player {}) reconnected",
+            vacancy.name,
+            vacancy.seat + 1
+        ));
+        let connection = &mut self.connections[index];
+        connection.joined = true;
+        connection.seat = Some(vacancy.seat);
+        connection.name = vacancy.name.clone();
+        connection.token = vacancy.token.clone();
+        let welcome = ServerMessage::Welcome {
+            name: vacancy.name,
+            token: vacancy.token,
+            settings: self.settings,
+        };
+        self.send(index, &welcome);
+        self.send_chat_history(index);
+        self.send_waiting();
+        true
+    }
+
+    /// Pass on a seated player's chat message if it keeps to the rules
+    fn chat_from(&mut self, index: usize, text: &str) {
+        let connection = &self.connections[index];
+        if connection.seat.is_none() || !self.started {
+            let message = if connection.spectator {
+                "Spectators can't chat"
+            } else {
+                "Chat opens once the game starts"
+            };
+            let message = message.to_string();
+            self.send(index, &ServerMessage::Error { message });
+            return;
+        }
+
+        match chat::check_message(self.settings.chat_mode, self.table_size(), text) {
+            Ok(text) => {
+                let from = connection.name.clone();
+                self.send_chat(ChatMessage { from, text });
+            }
+            Err(error) => self.send(index, &ServerMessage::ChatRejected { error }),
+        }
+    }
+
+    /// Record a chat message and send it to everyone at the table, spectators included
+    fn send_chat(&mut self, message: ChatMessage) {
+        self.chat.push(message.clone());
+        for i in 0..self.connections.len() {
+            let connection = &self.connections[i];
+            if connection.seat.is_some() || connection.spectator {
+                let message = message.clone();
+                self.send(i, &ServerMessage::Chat { message });
+            }
+        }
+    }
+
+    /// Catch a rejoining player or new spectator up on the chat so far
+    fn send_chat_history(&mut self, index: usize) {
+        for message in self.chat.clone() {
+            self.send(index, &ServerMessage::Chat { message });
+        }
+    }
+
+    /// Turn a connection away with a reason, hanging up on it
+    fn refuse(&mut self, index: usize, message: &str) {
+        let message = message.to_string();
+        self.send(index, &ServerMessage::Error { message });
+        let _ = self.connections[index].writer.shutdown(Shutdown::Both);
+    }
+
+    /// Tell every seated client which dropped players the game is waiting for
+    fn send_waiting(&mut self) {
+        let seats = self.waiting_for();
+        for i in 0..self.connections.len() {
+            if self.connections[i].seat.is_some() {
+                let seats = seats.clone();
+                self.send(i, &ServerMessage::Waiting { seats });
+            }
+        }
+    }
+
+    /// The lobby as `member` sees it
+    fn lobby_for(&self, member: Member) -> Lobby {
+        let members = self
+            .order
+            .iter()
+            .filter_map(|&place| match place {
+                Member::Host => Some(LobbyMember {
+                    name: self.name.clone(),
+                    ready: true,
+                    is_host: true,
+                }),
+                Member::Client(id) => {
+                    self.connections
+                        .iter()
+                        .find(|c| c.id == id)
+                        .map(|connection| LobbyMember {
+                            name: connection.name.clone(),
+                            ready: connection.ready,
+                            is_host: false,
+                        })
+                }
+                Member::Bot(index) => Some(LobbyMember {
+                    name: self.bots[index].name.clone(),
+                    ready: true,
+                    is_host: false,
+                }),
+            })
+            .collect();
+        Lobby {
+            members,
+            you: self.order.iter().position(|&place| place == member),
+            spectators: self.spectators(),
+            settings: self.settings,
+        }
+    }
+
+    /// Send every client in the lobby, and every spectator, the latest lobby
+    fn send_lobby(&mut self) {
+        for i in 0..self.connections.len() {
+            let connection = &self.connections[i];
+            if connection.joined || connection.spectator {
+                let lobby = self.lobby_for(Member::Client(connection.id));
+                self.send(i, &ServerMessage::Lobby { lobby });
+            }
+        }
+    }
+
+    /// Send a message to one connection, hanging up on it if the write fails
+    fn send(&mut self, index: usize, message: &ServerMessage) {
+        let writer = &mut self.connections[index].writer;
+        if write_message(writer, message).is_err() {
+            // The reader thread then reports the connection closed
+            let _ = writer.shutdown(Shutdown::Both);
+        }
+    }
+}
+
+/// A player's connection to a `Host`
+pub struct Client {
+    pub name: String,            // Name the host accepted
+    pub token: String,           // Session token for `rejoin`
+    pub settings: TableSettings, // Table options when the host let us in
+    writer: TcpStream,
+    incoming: Receiver<ServerMessage>,
+    last_sent: Instant,
+}
+
+impl Client {
+    /// Connect to a host and wait for it to let this player in
+    pub fn connect<A: ToSocketAddrs>(addr: A, name: &str) -> io::Result<Self> {
+        let name = name.to_string();
+        Self::handshake(addr, &ClientMessage::Join { name })
+    }
+
+    /// Connect to a host as a spectator
+    pub fn watch<A: ToSocketAddrs>(addr: A, name: &str) -> io::Result<Self> {
+        let name = name.to_string();
+        Self::handshake(addr, &ClientMessage::Watch { name })
+    }
+
+    /// Reconnect to a host after a drop, taking back the seat `token` was given for
+    pub fn rejoin<A: ToSocketAddrs>(addr: A, token: &str) -> io::Result<Self> {
+        let token = token.to_string();
+        Self::handshake(addr, &ClientMessage::Rejoin { token })
+    }
+
+    /// Send `hello` and wait for the host's `Welcome`
+    fn handshake<A: ToSocketAddrs>(addr: A, hello: &ClientMessage) -> io::Result<Self> {
+        let addr = addr
+            .to_socket_addrs()?
+            .next()
+            .ok_or(io::ErrorKind::AddrNotAvailable)?;
+        let mut writer = TcpStream::connect_timeout(&addr, JOIN_TIMEOUT)?;
+        write_message(&mut writer, hello)?;
+
+        let stream = writer.try_clone()?;
+        stream.set_read_timeout(Some(JOIN_TIMEOUT))?;
+        let mut reader = BufReader::new(stream);
+        let (name, token, settings) = loop {
+            match read_message(&mut reader)? {
+                Some(ServerMessage::Welcome {
+                    name,
+                    token,
+                    settings,
+                }) => break (name, token, settings),
+                Some(ServerMessage::Error { message }) => {
+                    return Err(io::Error::new(io::ErrorKind::ConnectionRefused, message));
+                }
+                Some(_) => {}
+                None => return Err(io::ErrorKind::UnexpectedEof.into()),
+            }
+        };
+        reader
+            .get_ref()
+            .set_read_timeout(Some(CONNECTION_TIMEOUT))?;
+
+        let (tx, incoming) = mpsc::channel();
+        thread::spawn(move || {
+            // Dropping `tx` when the host goes away, or goes quiet, tells the
+            // client it is disconnected
+            while let Ok(Some(message)) = read_message::<_, ServerMessage>(&mut reader) {
+                if tx.send(message).is_err() {
+                    break;
+                }
+            }
+            let _ = reader.get_ref().shutdown(Shutdown::Both);
+        });
+
+        Ok(Self {
+            name,
+            token,
+            settings,
+            writer,
+            incoming,
+            last_sent: Instant::now(),
+        })
+    }
+
+    /// Ask the host to apply an action for this client's seat
+    pub fn send(&mut self, action: GameAction) -> io::Result<()> {
+        self.send_message(&ClientMessage::Action { action })
+    }
+
+    /// Tell the host whether this player is ready to start
+    pub fn set_ready(&mut self, ready: bool) -> io::Result<()> {
+        self.send_message(&ClientMessage::Ready { ready })
+    }
+
+    /// Say something at the table; the host checks it against the chat rules
+    pub fn chat(&mut self, text: &str) -> io::Result<()> {
+        let text = text.to_string();
+        self.send_message(&ClientMessage::Chat { text })
+    }
+
+    fn send_message(&mut self, message: &ClientMessage) -> io::Result<()> {
+        self.last_sent = Instant::now();
+        write_message(&mut self.writer, message)
+    }
+
+    /// The next message from the host, if one has arrived; fails once the host
+    /// is gone. Also keeps the connection alive, so call it regularly.
+    pub fn try_recv(&mut self) -> io::Result<Option<ServerMessage>> {
+        if self.last_sent.elapsed() >= HEARTBEAT_INTERVAL {
+            self.send_message(&ClientMessage::Ping)?;
+        }
+        match self.incoming.try_recv() {
+            Ok(message) => Ok(Some(message)),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(io::ErrorKind::ConnectionAborted.into()),
+        }
+    }
+
+    /// Wait up to `timeout` for the next message from the host
+    pub fn recv_timeout(&self, timeout: Duration) -> io::Result<Option<ServerMessage>> {
+        match self.incoming.recv_timeout(timeout) {
+            Ok(message) => Ok(Some(message)),
+            Err(RecvTimeoutError::Timeout) => Ok(None),
+            Err(RecvTimeoutError::Disconnected) => Err(io::ErrorKind::ConnectionAborted.into()),
+        }
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        // The reader thread holds a clone of the socket, so close it explicitly
+        let _ = self.writer.shutdown(Shutdown::Both);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::HeuristicBot;
+    use crate::card::{Card, Rank, Suit};
+    use crate::game::Phase;
+    use std::time::Instant;
+
+    /// Poll the host until `done` holds, failing the test after a few seconds
+    fn poll_until<F: FnMut(&Host, &Game) -> bool>(host: &mut Host, game: &mut Game, mut done: F) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !done(host, game) {
+            assert!(Instant::now() < deadline, "timed out waiting for the host");
+            host.poll(game);
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    /// Wait for a message to `client` that `pick` accepts, polling the host meanwhile
+    fn receive<T, F: Fn(ServerMessage) -> Option<T>>(
+        host: &mut Host,
+        game: &mut Game,
+        client: &Client,
+        pick: F,
+    ) -> T {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            assert!(Instant::now() < deadline, "timed out waiting for a message");
+            host.poll(game);
+            if let Some(picked) = client
+                .recv_timeout(Duration::from_millis(5))
+                .unwrap()
+                .and_then(&pick)
+            {
+                return picked;
+            }
+        }
+    }
+
+    /// Wait for a state sent to `client` that satisfies `wanted`
+    fn view_where<F: Fn(&GameView) -> bool>(
+        host: &mut Host,
+        game: &mut Game,
+        client: &Client,
+        wanted: F,
+    ) -> GameView {
+        receive(host, game, client, |message| match message {
+            ServerMessage::State { view } if wanted(&view) => Some(view),
+            _ => None,
+        })
+    }
+
+    fn join(host: &mut Host, game: &mut Game, name: &'static str) -> Client {
+        let addr = host.local_addr().unwrap();
+        let handle = thread::spawn(move || Client::connect(addr, name));
+        let members = host.table_size();
+        poll_until(host, game, |_, _| handle.is_finished());
+        let client = handle.join().unwrap().unwrap();
+        assert_eq!(host.table_size(), members + 1);
+        client
+    }
+
+    #[test]
+    fn test_message_lines_roundtrip() {
+        let mut buffer = Vec::new();
+        let message = ClientMessage::Action {
+            action: GameAction::Play(vec![0, 2]),
+        };
+        write_message(&mut buffer, &message).unwrap();
+        assert!(buffer.ends_with(b"\n"));
+
+        let mut reader = io::Cursor::new(buffer);
+        let read: Option<ClientMessage> = read_message(&mut reader).unwrap();
+        assert_eq!(read, Some(message));
+        assert_eq!(read_message::<_, ClientMessage>(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn test_lobby_needs_two_to_four_ready_players() {
+        let member = |ready, is_host| LobbyMember {
+            name: "Someone".to_string(),
+            ready,
+            is_host,
+        };
+        let mut lobby = Lobby {
+            members: vec![member(true, true)],
+            you: Some(0),
+            spectators: 0,
+            settings: TableSettings::default(),
+        };
+        assert!(!lobby.can_start(), "nobody to play with");
+
+        lobby.members.push(member(false, false));
+        assert!(!lobby.can_start());
+        assert_eq!(lobby.ready_count(), 1);
+
+        lobby.members[1].ready = true;
+        assert!(lobby.can_start());
+
+        lobby
+            .members
+            .extend([member(true, false), member(true, false)]);
+        assert!(lobby.can_start());
+        lobby.members.push(member(true, false));
+        assert!(!lobby.can_start(), "too many players");
+    }
+
+    #[test]
+    fn test_host_and_clients_over_localhost() {
+        let mut game = Game::new_with_seed(2, 404);
+        let mut host = Host::bind("127.0.0.1:0", "Hosty").unwrap();
+
+        // Players gather in the lobby in the order they join
+        let mut alice = join(&mut host, &mut game, "Alice");
+        let mut bob = join(&mut host, &mut game, "Bob");
+        assert_eq!(alice.name, "Alice");
+        let names: Vec<_> = host.lobby().members.into_iter().map(|m| m.name).collect();
+        assert_eq!(names, ["Hosty", "Alice", "Bob"]);
+        assert!(!host.lobby().can_start());
+
+        // Everyone gets ready, and the host moves Bob to the first seat
+        alice.set_ready(true).unwrap();
+        bob.set_ready(true).unwrap();
+        poll_until(&mut host, &mut game, |host, _| host.lobby().can_start());
+        host.move_member(2, 0);
+        let lobby = receive(&mut host, &mut game, &alice, |message| match message {
+            ServerMessage::Lobby { lobby } if lobby.members[0].name == "Bob" => Some(lobby),
+            _ => None,
+        });
+        assert_eq!(lobby.you, Some(2));
+        assert!(lobby.members[1].is_host);
+
+        // The game is dealt for the lobby and seats follow its order
+        let mut game = Game::new_with_seed(host.table_size(), 404);
+        assert_eq!(host.start(&mut game), 1);
+        assert_eq!(host.seated(), [0, 2]);
+        assert_eq!(game.players[0].name, "Bob");
+        assert_eq!(game.players[1].name, "Hosty");
+
+        // Each client only sees its own hand
+        let view = view_where(&mut host, &mut game, &alice, |_| true);
+        assert_eq!(view.seat, 2);
+        assert_eq!(view.hand(), game.players[2].hand.as_slice());
+        assert!(view.players[0].hand.is_none());
+        assert_eq!(view.players[1].hand_size, 6);
+        view_where(&mut host, &mut game, &bob, |_| true);
+
+        // Nobody else can join a full table
+        let addr = host.local_addr().unwrap();
+        let handle = thread::spawn(move || Client::connect(addr, "Carol"));
+        poll_until(&mut host, &mut game, |_, _| handle.is_finished());
+        assert!(handle.join().unwrap().is_err());
+
+        // Acting out of turn is rejected
+        alice.send(GameAction::Yield).unwrap();
+        let error = receive(&mut host, &mut game, &alice, |message| match message {
+            ServerMessage::Rejected { error } => Some(error),
+            _ => None,
+        });
+        assert_eq!(error, RuleError::NotYourTurn { current: 0 });
+
+        // Bob plays a Jester and hands the turn to Alice, who then yields
+        game.players[0].hand[0] = Card::new(Suit::Hearts, Rank::Jester);
+        bob.send(GameAction::Play(vec![0])).unwrap();
+        poll_until(&mut host, &mut game, |_, game| {
+            game.phase == Phase::ChooseNextPlayer
+        });
+        bob.send(GameAction::ChooseNextPlayer(2)).unwrap();
+        view_where(&mut host, &mut game, &alice, |view| view.is_my_turn());
+
+        alice.send(GameAction::Yield).unwrap();
+        poll_until(&mut host, &mut game, |_, game| game.phase != Phase::Play);
+        assert!(matches!(game.phase, Phase::Discard { .. }) || game.is_over());
+        let phase = game.phase;
+        let view = view_where(&mut host, &mut game, &bob, |view| view.phase == phase);
+        assert_eq!(view.current_player, 2);
+        assert_eq!(view.seat, 0);
+
+        // A client that drops leaves its seat waiting for it
+        drop(bob);
+        poll_until(&mut host, &mut game, |host, _| !host.seated().contains(&0));
+        assert_eq!(host.waiting_for(), [0]);
+        assert!(game
+            .game_log
+            .last()
+            .unwrap()
+            .ends_with("Bob (Player 1) disconnected"));
+    }
+
+    #[test]
+    fn test_bot_fills_an_empty_seat() {
+        let mut host = Host::bind("127.0.0.1:0", "Hosty").unwrap();
+        assert!(host.add_bot(Box::new(HeuristicBot)));
+        let lobby = host.lobby();
+        assert_eq!(lobby.members[1].name, "Bot 1 (heuristic)");
+        assert!(lobby.can_start(), "bots are always ready");
+
+        let mut game = Game::new_with_seed(host.table_size(), 31);
+        assert_eq!(host.start(&mut game), 0);
+        assert_eq!(game.players[1].name, "Bot 1 (heuristic)");
+
+        // The host plays until the turn passes to the bot, which plays it back
+        let mut player = HeuristicBot;
+        while game.current_player == 0 && !game.is_over() {
+            let view = GameView::for_seat(&game, 0);
+            game.apply_as(0, player.choose(&view)).unwrap();
+        }
+        assert_eq!(game.current_player, 1);
+        let actions = game.actions.len();
+        assert!(host.poll(&mut game));
+        assert!(game.actions.len() > actions);
+        assert!(game.current_player == 0 || game.is_over());
+        assert!(
+            !host.add_bot(Box::new(HeuristicBot)),
+            "the game has started"
+        );
+    }
+
+    #[test]
+    fn test_client_that_stops_reading_is_dropped() {
+        let mut game = Game::new_with_seed(2, 5);
+        let mut host = Host::bind("127.0.0.1:0", "Hosty").unwrap();
+
+        // A client joins with a long name, so every lobby update is large, then never reads
+        let mut stalled = TcpStream::connect(host.local_addr().unwrap()).unwrap();
+        let name = "z".repeat(64 * 1024);
+        write_message(&mut stalled, &ClientMessage::Join { name }).unwrap();
+        poll_until(&mut host, &mut game, |host, _| host.table_size() == 2);
+
+        // Lobby updates pile up until a write times out and the client is let go
+        let deadline = Instant::now() + CONNECTION_TIMEOUT * 4;
+        while host.table_size() == 2 {
+            assert!(Instant::now() < deadline, "the host is stuck writing");
+            let started = Instant::now();
+            let mut settings = host.settings();
+            settings.spectators_see_hands = !settings.spectators_see_hands;
+            host.set_settings(settings);
+            host.poll(&mut game);
+            assert!(started.elapsed() < CONNECTION_TIMEOUT * 2);
+        }
+        drop(stalled);
+    }
+
+    #[test]
+    fn test_dropped_player_rejoins_with_token() {
+        let mut game = Game::new_with_seed(2, 77);
+        let mut host = Host::bind("127.0.0.1:0", "Hosty").unwrap();
+        let mut alice = join(&mut host, &mut game, "Alice");
+        let mut bob = join(&mut host, &mut game, "Bob");
+        alice.set_ready(true).unwrap();
+        bob.set_ready(true).unwrap();
+        poll_until(&mut host, &mut game, |host, _| host.lobby().can_start());
+        let mut game = Game::new_with_seed(host.table_size(), 77);
+        host.start(&mut game);
+        assert_ne!(alice.token, bob.token);
+
+        // Bob's connection drops and the game pauses for him
+        let token = bob.token.clone();
+        drop(bob);
+        poll_until(&mut host, &mut game, |host, _| host.is_paused());
+        let seats = receive(&mut host, &mut game, &alice, |message| match message {
+            ServerMessage::Waiting { seats } => Some(seats),
+            _ => None,
+        });
+        assert_eq!(seats, [2]);
+        assert_eq!(
+            host.pause_notice(&game).unwrap(),
+            "⏸ Waiting for Bob to reconnect…"
+        );
+
+        alice.send(GameAction::Yield).unwrap();
+        let message = receive(&mut host, &mut game, &alice, |message| match message {
+            ServerMessage::Error { message } => Some(message),
+            _ => None,
+        });
+        assert!(message.contains("Waiting for Bob"));
+
+        // Only the right token gets the seat back
+        let addr = host.local_addr().unwrap();
+        let handle = thread::spawn(move || Client::rejoin(addr, "not-a-token"));
+        poll_until(&mut host, &mut game, |_, _| handle.is_finished());
+        assert!(handle.join().unwrap().is_err());
+
+        let handle = thread::spawn(move || Client::rejoin(addr, &token));
+        poll_until(&mut host, &mut game, |_, _| handle.is_finished());
+        let bob = handle.join().unwrap().unwrap();
+        assert_eq!(bob.name, "Bob");
+        assert!(!host.is_paused());
+        assert!(game
+            .game_log
+            .last()
+            .unwrap()
+            .ends_with("Bob (Player 3) reconnected"));
+
+        // He is told play is back on, and sent the game as it stands
+        let seats = receive(&mut host, &mut game, &bob, |message| match message {
+            ServerMessage::Waiting { seats } => Some(seats),
+            _ => None,
+        });
+        assert!(seats.is_empty());
+        let view = view_where(&mut host, &mut game, &bob, |_| true);
+        assert_eq!(view.seat, 2);
+        assert_eq!(view.hand(), game.players[2].hand.as_slice());
+    }
+
+    #[test]
+    fn test_spectators_watch_without_a_seat() {
+        let mut game = Game::new_with_seed(2, 5);
+        let mut host = Host::bind("127.0.0.1:0", "Hosty").unwrap();
+        let mut alice = join(&mut host, &mut game, "Alice");
+
+        // A spectator in the lobby doesn't take a place at the table
+        let addr = host.local_addr().unwrap();
+        let handle = thread::spawn(move || Client::watch(addr, "Carol"));
+        poll_until(&mut host, &mut game, |_, _| handle.is_finished());
+        let mut carol = handle.join().unwrap().unwrap();
+        assert_eq!(host.table_size(), 2);
+        assert_eq!(host.spectators(), 1);
+
+        host.set_settings(TableSettings {
+            spectators_see_hands: true,
+            ..TableSettings::default()
+        });
+        let lobby = receive(&mut host, &mut game, &carol, |message| match message {
+            ServerMessage::Lobby { lobby } if lobby.settings.spectators_see_hands => Some(lobby),
+            _ => None,
+        });
+        assert_eq!(lobby.you, None);
+        assert_eq!(lobby.spectators, 1);
+
+        alice.set_ready(true).unwrap();
+        poll_until(&mut host, &mut game, |host, _| host.lobby().can_start());
+        let mut game = Game::new_with_seed(host.table_size(), 5);
+        host.start(&mut game);
+
+        // The spectator sees every hand, as allowed, but can't act
+        let view = view_where(&mut host, &mut game, &carol, |_| true);
+        assert!(view.spectating);
+        assert_eq!(view.players[1].hand.as_ref(), Some(&game.players[1].hand));
+        carol.send(GameAction::Yield).unwrap();
+        let message = receive(&mut host, &mut game, &carol, |message| match message {
+            ServerMessage::Error { message } => Some(message),
+            _ => None,
+        });
+        assert_eq!(message, "Spectators can't take actions");
+        assert!(game.actions.is_empty());
+
+        // Spectators can also arrive once the game is under way
+        let handle = thread::spawn(move || Client::watch(addr, "Dave"));
+        poll_until(&mut host, &mut game, |_, _| handle.is_finished());
+        let dave = handle.join().unwrap().unwrap();
+        let view = view_where(&mut host, &mut game, &dave, |_| true);
+        assert!(view.game_log.last().unwrap().ends_with("Dave is watching"));
+    }
+
+    #[test]
+    fn test_chat_keeps_to_the_table_rules() {
+        let mut game = Game::new_with_seed(2, 6);
+        let mut host = Host::bind("127.0.0.1:0", "Hosty").unwrap();
+        let mut alice = join(&mut host, &mut game, "Alice");
+        host.set_settings(TableSettings {
+            chat_mode: ChatMode::Strict,
+            ..TableSettings::default()
+        });
+
+        // No chat until the game starts
+        alice.chat("Good luck!").unwrap();
+        let message = receive(&mut host, &mut game, &alice, |message| match message {
+            ServerMessage::Error { message } => Some(message),
+            _ => None,
+        });
+        assert_eq!(message, "Chat opens once the game starts");
+
+        alice.set_ready(true).unwrap();
+        poll_until(&mut host, &mut game, |host, _| host.lobby().can_start());
+        host.start(&mut game);
+
+        // Strict chat turns away anything but the signals
+        alice.chat("I have a big heart").unwrap();
+        let error = receive(&mut host, &mut game, &alice, |message| match message {
+            ServerMessage::ChatRejected { error } => Some(error),
+            _ => None,
+        });
+        assert_eq!(error, ChatError::NotASignal);
+        assert!(host.chat().is_empty());
+
+        alice.chat("please yield").unwrap();
+        let message = receive(&mut host, &mut game, &alice, |message| match message {
+            ServerMessage::Chat { message } => Some(message),
+            _ => None,
+        });
+        assert_eq!(message.from, "Alice");
+        assert_eq!(message.text, "Please yield");
+
+        // The host's own messages follow the same rules
+        assert_eq!(host.say("Play your ace"), Err(ChatError::NotASignal));
+        host.say("Give me a moment").unwrap();
+        assert_eq!(host.chat().len(), 2);
+
+        // Spectators hear the table talk so far but can't join in
+        let addr = host.local_addr().unwrap();
+        let handle = thread::spawn(move || Client::watch(addr, "Carol"));
+        poll_until(&mut host, &mut game, |_, _| handle.is_finished());
+        let mut carol = handle.join().unwrap().unwrap();
+        assert_eq!(carol.settings.chat_mode, ChatMode::Strict);
+        let message = receive(&mut host, &mut game, &carol, |message| match message {
+            ServerMessage::Chat { message } => Some(message),
+            _ => None,
+        });
+        assert_eq!(message.text, "Please yield");
+        carol.chat("Well played!").unwrap();
+        let message = receive(&mut host, &mut game, &carol, |message| match message {
+            ServerMessage::Error { message } => Some(message),
+            _ => None,
+        });
+        assert_eq!(message, "Spectators can't chat");
+    }
+}
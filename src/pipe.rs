@@ -0,0 +1,95 @@
+use crate::game::Game;
+use crate::net::{read_message, write_message, ClientMessage, ServerMessage};
+use crate::view::GameView;
+use std::io::{self, BufRead, Write};
+
+/// Let a bot in another process play `game` over a pipe, speaking the LAN
+/// protocol's JSON lines: a `State` for the player to move after every
+/// step, and `Rejected` or `Error` when a line can't be used. The bot
+/// answers each state with an `Action`. Returns once the game is over, or
+/// when the bot closes its end.
+pub fn serve<R: BufRead, W: Write>(game: &mut Game, mut input: R, mut output: W) -> io::Result<()> {
+    let state = |game: &Game| ServerMessage::State {
+        view: GameView::for_seat(game, game.current_player),
+    };
+
+    write_message(&mut output, &state(game))?;
+    while !game.is_over() {
+        let reply = match read_message(&mut input) {
+            Ok(Some(ClientMessage::Action { action })) => match game.apply(action) {
+                Ok(_) => state(game),
+                Err(error) => ServerMessage::Rejected { error },
+            },
+            Ok(Some(ClientMessage::Ping)) => ServerMessage::Ping,
+            Ok(Some(_)) => ServerMessage::Error {
+                message: "Only actions can be sent to a bot server".to_string(),
+            },
+            Ok(None) => return Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => ServerMessage::Error {
+                message: e.to_string(),
+            },
+            Err(e) => return Err(e),
+        };
+        write_message(&mut output, &reply)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RuleError;
+    use crate::game::GameState;
+
+    fn replies(game: &mut Game, input: &str) -> Vec<ServerMessage> {
+        let mut output = Vec::new();
+        serve(game, input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_bot_server_answers_each_line() {
+        let mut game = Game::new_solo_with_seed(6);
+        let input = [
+            r#"{"type":"action","action":{"Play":[99]}}"#,
+            r#"{"type":"action","action":{"Play":[0]}}"#,
+            "not json",
+            r#"{"type":"ready","ready":true}"#,
+        ]
+        .join("\n");
+
+        let replies = replies(&mut game, &input);
+        assert_eq!(replies.len(), 5);
+        assert!(matches!(&replies[0], ServerMessage::State { view } if view.hand().len() == 8));
+        assert!(matches!(
+            replies[1],
+            ServerMessage::Rejected {
+                error: RuleError::InvalidIndex(99)
+            }
+        ));
+        assert!(matches!(&replies[2], ServerMessage::State { .. }));
+        assert!(matches!(replies[3], ServerMessage::Error { .. }));
+        assert!(matches!(replies[4], ServerMessage::Error { .. }));
+        assert_eq!(game.actions.len(), 1);
+    }
+
+    #[test]
+    fn test_bot_server_stops_when_the_game_ends() {
+        let mut game = Game::new_solo_with_seed(6);
+        game.players[0].hand.clear();
+        game.jesters_used = game.jester_count;
+        game.shield_value = 0;
+
+        // With nothing to play or discard, a yield loses; later lines go unread
+        let input = r#"{"type":"action","action":"Yield"}
+{"type":"action","action":"Yield"}"#;
+        let replies = replies(&mut game, input);
+        assert_eq!(replies.len(), 2);
+        assert!(matches!(&replies[1], ServerMessage::State { view }
+            if matches!(view.game_state, GameState::Defeat(_))));
+    }
+}
@@ -1,6 +1,30 @@
 use crate::card::Card;
+use crate::enemy::Enemy;
 use serde::{Deserialize, Serialize};
 
+/// The least wasteful cards in `hand` to discard against an attack of
+/// `required`, as sorted indices, or `None` if the whole hand falls short.
+/// Prefers the smallest total over `required`, then the fewest cards, then
+/// keeping cards whose suit powers still work against `enemy`.
+pub fn best_discard_in(hand: &[Card], required: u8, enemy: Option<&Enemy>) -> Option<Vec<usize>> {
+    let useful = |card: &Card| enemy.is_none_or(|enemy| !enemy.is_immune_to(card.suit));
+
+    (0..1u32 << hand.len())
+        .map(|mask| {
+            (0..hand.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .collect::<Vec<usize>>()
+        })
+        .filter_map(|indices| {
+            let total: u32 = indices.iter().map(|&i| hand[i].value() as u32).sum();
+            let over = total.checked_sub(required as u32)?;
+            let useful_lost = indices.iter().filter(|&&i| useful(&hand[i])).count();
+            Some(((over, indices.len(), useful_lost), indices))
+        })
+        .min()
+        .map(|(_, indices)| indices)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
     pub hand: Vec<Card>,
@@ -69,6 +93,12 @@ impl Player {
             .sum()
     }
 
+    /// The least wasteful discard to survive `required` damage from `enemy`;
+    /// see `best_discard_in`
+    pub fn best_discard(&self, required: u8, enemy: Option<&Enemy>) -> Option<Vec<usize>> {
+        best_discard_in(&self.hand, required, enemy)
+    }
+
     /// Check if player can discard enough to survive damage
     pub fn can_survive(&self, damage: u8) -> bool {
         let total_value: u8 = self.hand.iter().map(|c| c.value()).sum();
@@ -85,3 +115,58 @@ impl Player {
         self.hand.len() >= self.max_hand_size
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Rank, Suit};
+
+    fn player_with(hand: Vec<Card>) -> Player {
+        let mut player = Player::new("Hero".to_string(), 8);
+        player.hand = hand;
+        player
+    }
+
+    #[test]
+    fn test_best_discard_wastes_least_value() {
+        let player = player_with(vec![
+            Card::new(Suit::Hearts, Rank::Ten),
+            Card::new(Suit::Clubs, Rank::Four),
+            Card::new(Suit::Spades, Rank::Three),
+            Card::new(Suit::Diamonds, Rank::Two),
+        ]);
+
+        // 4 + 3 covers 7 exactly, where the Ten would waste 3
+        assert_eq!(player.best_discard(7, None), Some(vec![1, 2]));
+        assert_eq!(player.best_discard(20, None), None);
+        assert_eq!(player.best_discard(0, None), Some(vec![]));
+    }
+
+    #[test]
+    fn test_best_discard_prefers_fewer_cards() {
+        let player = player_with(vec![
+            Card::new(Suit::Hearts, Rank::Six),
+            Card::new(Suit::Clubs, Rank::Ten),
+            Card::new(Suit::Spades, Rank::Four),
+        ]);
+
+        // The Ten and 6 + 4 both cover 10 exactly; one card beats two
+        assert_eq!(player.best_discard(10, None), Some(vec![1]));
+    }
+
+    #[test]
+    fn test_best_discard_keeps_suits_that_work_against_the_enemy() {
+        let player = player_with(vec![
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Clubs, Rank::Five),
+            Card::new(Suit::Spades, Rank::Jester),
+        ]);
+
+        // Against the King of Clubs, the Five of Clubs has no power left to lose
+        let enemy = Enemy::new(Card::new(Suit::Clubs, Rank::King));
+        assert_eq!(player.best_discard(5, Some(&enemy)), Some(vec![1]));
+
+        let enemy = Enemy::new(Card::new(Suit::Hearts, Rank::King));
+        assert_eq!(player.best_discard(5, Some(&enemy)), Some(vec![0]));
+    }
+}
@@ -0,0 +1,176 @@
+use crate::action::GameAction;
+use crate::error::RuleError;
+use crate::game::Game;
+use crate::save;
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A recorded game: the seed and player count plus every accepted action, in order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Replay {
+    pub seed: u64,
+    #[serde(default = "solo")]
+    pub players: usize,
+    pub actions: Vec<GameAction>,
+}
+
+/// Replays recorded before multiplayer were all solo games
+fn solo() -> usize {
+    1
+}
+
+impl Replay {
+    /// Record the actions taken so far in a game
+    pub fn from_game(game: &Game) -> Self {
+        Self {
+            seed: game.seed,
+            players: game.players.len(),
+            actions: game.actions.clone(),
+        }
+    }
+
+    /// Number of recorded actions
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// Check if no actions were recorded
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Rebuild the game as it was after the first `step` actions
+    pub fn game_at(&self, step: usize) -> Result<Game, RuleError> {
+        let mut game = Game::new_with_seed(self.players, self.seed);
+        for action in self.actions.iter().take(step) {
+            game.apply(action.clone())?;
+        }
+        Ok(game)
+    }
+
+    /// Write the replay as JSON, creating parent directories as needed
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    /// Read a replay written with `save_to`, checking it is for a table
+    /// the game can deal
+    pub fn load_from(path: &Path) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        let replay: Self = serde_json::from_str(&json)?;
+        if !(1..=4).contains(&replay.players) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Replay is for {} players, not 1-4", replay.players),
+            ));
+        }
+        Ok(replay)
+    }
+}
+
+/// Directory where finished games are recorded
+pub fn replay_dir() -> Option<PathBuf> {
+    save::data_dir().map(|dir| dir.join("replays"))
+}
+
+/// Record a game to a new timestamped file in the replay directory
+pub fn save_replay(game: &Game) -> io::Result<PathBuf> {
+    let dir = replay_dir()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No data directory available"))?;
+    let name = format!(
+        "{}-seed-{}.json",
+        Local::now().format("%Y%m%d-%H%M%S"),
+        game.seed
+    );
+    let path = dir.join(name);
+    Replay::from_game(game).save_to(&path)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Card, Rank, Suit};
+
+    #[test]
+    fn test_replay_reproduces_game() {
+        let mut game = Game::new_solo_with_seed(2024);
+        game.apply(GameAction::Play(vec![0])).unwrap();
+        if game.phase != crate::game::Phase::Play {
+            let all: Vec<usize> = (0..game.players[0].hand.len()).collect();
+            game.apply(GameAction::Discard(all)).unwrap();
+        }
+        game.apply(GameAction::UseSoloJester).unwrap();
+
+        let replay = Replay::from_game(&game);
+        assert_eq!(replay.seed, 2024);
+        assert_eq!(replay.players, 1);
+        assert_eq!(replay.len(), game.actions.len());
+
+        let replayed = replay.game_at(replay.len()).unwrap();
+        assert_eq!(replayed.players[0].hand, game.players[0].hand);
+        assert_eq!(replayed.tavern_deck.cards, game.tavern_deck.cards);
+        assert_eq!(replayed.discard_pile, game.discard_pile);
+        assert_eq!(replayed.events, game.events);
+
+        // Step 0 is the fresh deal
+        let start = replay.game_at(0).unwrap();
+        assert_eq!(start.players[0].hand.len(), 8);
+        assert!(start.actions.is_empty());
+    }
+
+    #[test]
+    fn test_rejected_actions_are_not_recorded() {
+        let mut game = Game::new_solo_with_seed(1);
+        game.players[0].hand[0] = Card::new(Suit::Hearts, Rank::Six);
+        game.players[0].hand[1] = Card::new(Suit::Clubs, Rank::Seven);
+        assert!(game.apply(GameAction::Play(vec![0, 1])).is_err());
+        assert!(game.actions.is_empty());
+    }
+
+    #[test]
+    fn test_replay_file_roundtrip() {
+        let path = std::env::temp_dir()
+            .join(format!("kingslayer-replay-{}", std::process::id()))
+            .join("replay.json");
+        let mut game = Game::new_solo_with_seed(8);
+        game.apply(GameAction::Yield).unwrap();
+
+        let replay = Replay::from_game(&game);
+        replay.save_to(&path).unwrap();
+        assert_eq!(Replay::load_from(&path).unwrap(), replay);
+
+        // Files shared by others may name a table that can't be dealt
+        for players in [0, 5] {
+            Replay {
+                players,
+                ..replay.clone()
+            }
+            .save_to(&path)
+            .unwrap();
+            let error = Replay::load_from(&path).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_replay_reproduces_multiplayer_game() {
+        let mut game = Game::new_with_seed(3, 77);
+        game.apply(GameAction::Play(vec![0])).unwrap();
+
+        let replay = Replay::from_game(&game);
+        assert_eq!(replay.players, 3);
+        let replayed = replay.game_at(replay.len()).unwrap();
+        assert_eq!(replayed.players.len(), 3);
+        assert_eq!(replayed.current_player, game.current_player);
+        assert_eq!(replayed.events, game.events);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::ui;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use kingslayer::{Game, GameView, Replay, RuleError};
+use ratatui::Terminal;
+use std::io;
+
+/// Steps through a recorded game, rebuilding the state at each step
+pub struct ReplayViewer {
+    replay: Replay,
+    step: usize, // Number of actions applied to `game`
+    game: Game,
+    log_scroll_offset: usize,
+}
+
+impl ReplayViewer {
+    /// Load a replay, checking every recorded action still applies
+    pub fn new(replay: Replay) -> Result<Self, RuleError> {
+        replay.game_at(replay.len())?;
+        let game = replay.game_at(0)?;
+        Ok(Self {
+            replay,
+            step: 0,
+            game,
+            log_scroll_offset: 0,
+        })
+    }
+
+    fn go_to(&mut self, step: usize) {
+        let step = step.min(self.replay.len());
+        // Actions were validated in `new`, so rebuilding cannot fail
+        if let Ok(game) = self.replay.game_at(step) {
+            self.game = game;
+            self.step = step;
+            self.log_scroll_offset = 0;
+        }
+    }
+
+    fn step_forward(&mut self) {
+        self.go_to(self.step + 1);
+    }
+
+    fn step_back(&mut self) {
+        self.go_to(self.step.saturating_sub(1));
+    }
+
+    /// Describe the next recorded action using the hand it was taken from
+    fn describe_next(&self) -> String {
+        match self.replay.actions.get(self.step) {
+            Some(action) => action.describe(&self.game.player().hand),
+            None => "End of replay".to_string(),
+        }
+    }
+
+    fn action_prompt(&self) -> String {
+        format!(
+            "REPLAY (seed {}) step {}/{} | Next: {} | ←/→ step, Home/End jump, q quit",
+            self.replay.seed,
+            self.step,
+            self.replay.len(),
+            self.describe_next()
+        )
+    }
+}
+
+pub fn run<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    viewer: &mut ReplayViewer,
+) -> io::Result<()> {
+    loop {
+        terminal.draw(|f| {
+            let action_prompt = viewer.action_prompt();
+            let game = &viewer.game;
+            let view = GameView {
+                seed: Some(game.seed),
+                ..GameView::for_seat(game, game.current_player)
+            };
+            ui::render_game(
+                f,
+                &view,
+                ui::HandMarks::default(),
+                viewer.log_scroll_offset,
+                ui::InfoPane::Guide(0),
+                &action_prompt,
+            );
+        })?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Right | KeyCode::Char(' ') => viewer.step_forward(),
+                KeyCode::Left | KeyCode::Backspace => viewer.step_back(),
+                KeyCode::Home => viewer.go_to(0),
+                KeyCode::End => viewer.go_to(viewer.replay.len()),
+                KeyCode::Up if viewer.log_scroll_offset < viewer.game.game_log.len() => {
+                    viewer.log_scroll_offset += 1;
+                }
+                KeyCode::Down => {
+                    viewer.log_scroll_offset = viewer.log_scroll_offset.saturating_sub(1);
+                }
+                _ => {}
+            }
+        }
+    }
+}
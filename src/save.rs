@@ -0,0 +1,124 @@
+use crate::game::Game;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const SAVE_FILE: &str = "save.json";
+
+/// Directory for Kingslayer's data files, following the XDG base directory spec
+/// ($XDG_DATA_HOME, falling back to ~/.local/share; %APPDATA% on Windows)
+pub fn data_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            if cfg!(windows) {
+                std::env::var_os("APPDATA").map(PathBuf::from)
+            } else {
+                std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share"))
+            }
+        })?;
+    Some(base.join("kingslayer"))
+}
+
+/// Path of the saved game file
+pub fn save_path() -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join(SAVE_FILE))
+}
+
+fn no_data_dir() -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, "No data directory available")
+}
+
+/// Write the full game state to `path`, creating parent directories as needed
+pub fn save_to(path: &Path, game: &Game) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(game)?;
+    fs::write(path, json)
+}
+
+/// Read a game saved with `save_to`, or `None` if there is no save file
+pub fn load_from(path: &Path) -> io::Result<Option<Game>> {
+    match fs::read_to_string(path) {
+        Ok(json) => Ok(Some(serde_json::from_str(&json)?)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Save the game to the default save file, returning where it was written
+pub fn save_game(game: &Game) -> io::Result<PathBuf> {
+    let path = save_path().ok_or_else(no_data_dir)?;
+    save_to(&path, game)?;
+    Ok(path)
+}
+
+/// Load the game from the default save file, if there is one
+pub fn load_game() -> io::Result<Option<Game>> {
+    match save_path() {
+        Some(path) => load_from(&path),
+        None => Ok(None),
+    }
+}
+
+/// Check if a saved game is waiting to be resumed
+pub fn has_saved_game() -> bool {
+    save_path().is_some_and(|path| path.exists())
+}
+
+/// Remove the default save file (e.g. once the saved game has ended)
+pub fn delete_saved_game() -> io::Result<()> {
+    match save_path() {
+        Some(path) => match fs::remove_file(path) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        },
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::action::GameAction;
+    use crate::game::Phase;
+
+    fn temp_save(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("kingslayer-test-{}-{}", std::process::id(), name))
+            .join(SAVE_FILE)
+    }
+
+    #[test]
+    fn test_save_and_resume_restores_game_exactly() {
+        let path = temp_save("roundtrip");
+        let mut game = Game::new_solo_with_seed(99);
+        game.apply(GameAction::Yield).unwrap();
+
+        save_to(&path, &game).unwrap();
+        let resumed = load_from(&path).unwrap().expect("save file exists");
+
+        assert_eq!(resumed.seed, game.seed);
+        assert_eq!(resumed.phase, game.phase);
+        assert!(matches!(resumed.phase, Phase::Discard { .. }));
+        assert_eq!(resumed.players[0].hand, game.players[0].hand);
+        assert_eq!(resumed.tavern_deck.cards, game.tavern_deck.cards);
+        assert_eq!(resumed.events, game.events);
+
+        // Everything else, including the RNG state, round-trips too
+        assert_eq!(
+            serde_json::to_value(&resumed).unwrap(),
+            serde_json::to_value(&game).unwrap()
+        );
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_load_missing_save_is_none() {
+        let path = temp_save("missing");
+        assert!(load_from(&path).unwrap().is_none());
+    }
+}
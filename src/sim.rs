@@ -0,0 +1,157 @@
+use crate::bot::Bot;
+use crate::error::RuleError;
+use crate::event::GameEvent;
+use crate::game::{Game, GameState, VictoryRank};
+
+/// Actions after which a game is abandoned, in case a bot never finishes it
+pub const MAX_ACTIONS: usize = 2000;
+
+/// How one simulated game ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Victory(Option<VictoryRank>), // Ranked in solo games only
+    Defeat,
+    Stalled,             // Abandoned after `MAX_ACTIONS`
+    Rejected(RuleError), // The bot chose an action the rules reject
+}
+
+/// Totals over a batch of simulated games
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimReport {
+    pub games: usize,
+    pub wins: usize,
+    pub gold: usize,
+    pub silver: usize,
+    pub bronze: usize,
+    pub stalled: usize,
+    pub rejected: usize,         // Games ended by an illegal action, a bot bug
+    pub enemies_defeated: usize, // Captured or killed, over every game
+    pub jesters_used: usize,     // Solo Jester powers, over every game
+}
+
+impl SimReport {
+    /// Add one finished game to the totals
+    pub fn record(&mut self, game: &Game, outcome: Outcome) {
+        self.games += 1;
+        match outcome {
+            Outcome::Victory(rank) => {
+                self.wins += 1;
+                match rank {
+                    Some(VictoryRank::Gold) => self.gold += 1,
+                    Some(VictoryRank::Silver) => self.silver += 1,
+                    Some(VictoryRank::Bronze) => self.bronze += 1,
+                    None => {}
+                }
+            }
+            Outcome::Defeat => {}
+            Outcome::Stalled => self.stalled += 1,
+            Outcome::Rejected(_) => self.rejected += 1,
+        }
+        self.enemies_defeated += enemies_defeated(game);
+        self.jesters_used += game.jesters_used as usize;
+    }
+
+    /// Share of games won, from 0 to 1
+    pub fn win_rate(&self) -> f64 {
+        self.per_game(self.wins)
+    }
+
+    pub fn average_enemies_defeated(&self) -> f64 {
+        self.per_game(self.enemies_defeated)
+    }
+
+    pub fn average_jesters_used(&self) -> f64 {
+        self.per_game(self.jesters_used)
+    }
+
+    fn per_game(&self, total: usize) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            total as f64 / self.games as f64
+        }
+    }
+}
+
+/// Count the enemies captured or killed so far
+pub fn enemies_defeated(game: &Game) -> usize {
+    game.events
+        .iter()
+        .filter(|event| {
+            matches!(
+                event,
+                GameEvent::EnemyCaptured { .. } | GameEvent::EnemyDefeated { .. }
+            )
+        })
+        .count()
+}
+
+/// Let `bot` take every seat until the game ends
+pub fn play_out(game: &mut Game, bot: &mut dyn Bot) -> Outcome {
+    while !game.is_over() {
+        if game.actions.len() >= MAX_ACTIONS {
+            return Outcome::Stalled;
+        }
+        if let Err(e) = game.apply(bot.choose_from_game(game)) {
+            return Outcome::Rejected(e);
+        }
+    }
+    match game.victory_rank() {
+        Some(rank) => Outcome::Victory(Some(rank)),
+        None if matches!(game.game_state, GameState::Victory) => Outcome::Victory(None),
+        None => Outcome::Defeat,
+    }
+}
+
+/// Play `games` games with `bot` in every seat. Game `i` is dealt from
+/// `seed + i`, so any single game can be replayed with `--seed`.
+pub fn simulate(bot: &mut dyn Bot, players: usize, games: usize, seed: u64) -> SimReport {
+    let mut report = SimReport::default();
+    for i in 0..games {
+        let mut game = Game::new_with_seed(players, seed.wrapping_add(i as u64));
+        let outcome = play_out(&mut game, bot);
+        report.record(&game, outcome);
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::AdvisorBot;
+    use crate::view::GameView;
+
+    #[test]
+    fn test_simulation_is_reproducible() {
+        let first = simulate(&mut AdvisorBot, 1, 20, 7);
+        let second = simulate(&mut AdvisorBot, 1, 20, 7);
+        assert_eq!(first, second);
+
+        assert_eq!(first.games, 20);
+        assert_eq!(first.rejected, 0);
+        assert_eq!(first.wins, first.gold + first.silver + first.bronze);
+        assert!(first.enemies_defeated <= 20 * 12);
+        assert!(first.jesters_used <= 20 * 2);
+    }
+
+    #[test]
+    fn test_illegal_actions_end_the_game() {
+        struct Stubborn;
+        impl Bot for Stubborn {
+            fn name(&self) -> &'static str {
+                "stubborn"
+            }
+            fn choose(&mut self, _: &GameView) -> crate::action::GameAction {
+                crate::action::GameAction::Play(vec![99])
+            }
+        }
+
+        let mut game = Game::new_solo_with_seed(1);
+        assert_eq!(
+            play_out(&mut game, &mut Stubborn),
+            Outcome::Rejected(RuleError::InvalidIndex(99))
+        );
+        let report = simulate(&mut Stubborn, 1, 3, 1);
+        assert_eq!((report.games, report.rejected, report.wins), (3, 3, 0));
+    }
+}
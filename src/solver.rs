@@ -0,0 +1,271 @@
+use crate::action::GameAction;
+use crate::advisor::strike;
+use crate::card::Card;
+use crate::game::{Game, GameState};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Positions the solver may visit before giving up, by default
+pub const DEFAULT_NODE_LIMIT: usize = 2_000_000;
+
+/// What the solver found out about a deal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// `line` wins using `jesters` solo Jesters; `fewest` is true once every
+    /// smaller number of Jesters has been ruled out
+    Winnable {
+        jesters: u8,
+        line: Vec<GameAction>,
+        fewest: bool,
+    },
+    /// No line wins, whatever the Jesters
+    Unwinnable,
+    /// The node limit ran out before a win was found or ruled out
+    Unknown,
+}
+
+/// The node limit ran out
+struct OutOfNodes;
+
+/// Depth-first search over every line of play, with the whole deal known.
+/// Every discard that covers the attack is tried, not just the smallest: a
+/// fuller hand draws fewer cards from Diamonds, and a discarded card can be
+/// healed back into the tavern by Hearts.
+pub struct Solver {
+    limit: usize,
+    nodes: usize,
+    stop_at: usize,           // Node count at which the current search gives up
+    failed: HashMap<u64, u8>, // Positions lost with up to this many Jesters to spare
+}
+
+impl Solver {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            nodes: 0,
+            stop_at: limit,
+            failed: HashMap::new(),
+        }
+    }
+
+    /// Positions visited so far
+    pub fn nodes(&self) -> usize {
+        self.nodes
+    }
+
+    /// Find a winning line from `game` with as few solo Jesters as possible
+    pub fn solve(&mut self, game: &Game) -> Verdict {
+        let mut root = game.clone();
+        forget_history(&mut root);
+        let jesters_left = game.jester_count.saturating_sub(game.jesters_used);
+
+        let mut fewest = true;
+        for spare in 0..=jesters_left {
+            // Share what is left of the limit between the Jester counts still to try
+            let tries_left = (jesters_left - spare + 1) as usize;
+            self.stop_at = self.nodes + (self.limit - self.nodes) / tries_left;
+            match self.search(&root, spare) {
+                Ok(Some(mut line)) => {
+                    line.reverse();
+                    return Verdict::Winnable {
+                        jesters: spare,
+                        line,
+                        fewest,
+                    };
+                }
+                Ok(None) => {}
+                Err(OutOfNodes) => fewest = false,
+            }
+        }
+        if fewest {
+            Verdict::Unwinnable
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// A winning line from `game`, using at most `spare` solo Jesters, in reverse
+    fn search(&mut self, game: &Game, spare: u8) -> Result<Option<Vec<GameAction>>, OutOfNodes> {
+        match game.game_state {
+            GameState::Victory => return Ok(Some(Vec::new())),
+            GameState::Defeat(_) => return Ok(None),
+            GameState::Playing => {}
+        }
+        if self.nodes >= self.stop_at {
+            return Err(OutOfNodes);
+        }
+        self.nodes += 1;
+
+        // Positions are compared by the Jesters that can really still be used
+        let spare = spare.min(game.jester_count.saturating_sub(game.jesters_used));
+        let key = position_key(game);
+        if self.failed.get(&key).is_some_and(|&lost| lost >= spare) {
+            return Ok(None);
+        }
+
+        for action in candidate_actions(game, spare > 0) {
+            let mut next = game.clone();
+            if next.apply(action.clone()).is_err() {
+                continue;
+            }
+            forget_history(&mut next);
+            let spent = next.jesters_used - game.jesters_used;
+            if let Some(mut line) = self.search(&next, spare - spent)? {
+                line.push(action);
+                return Ok(Some(line));
+            }
+        }
+
+        let lost = self.failed.entry(key).or_insert(spare);
+        *lost = (*lost).max(spare);
+        Ok(None)
+    }
+}
+
+/// Check whether a seeded solo deal can be won, and with how few Jesters
+pub fn solve_seed(seed: u64, limit: usize) -> Verdict {
+    Solver::new(limit).solve(&Game::new_solo_with_seed(seed))
+}
+
+/// The log and history play no part in the rules, so drop them to keep
+/// clones cheap
+fn forget_history(game: &mut Game) {
+    game.game_log.clear();
+    game.events.clear();
+    game.actions.clear();
+}
+
+/// Hash everything that decides how the rest of the game can go. Jesters
+/// are left out: the search tracks how many it may still use.
+fn position_key(game: &Game) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for player in &game.players {
+        // Hand order only changes the indices of the same plays
+        let mut hand = player.hand.clone();
+        hand.sort_by_key(|c| (c.suit as u8, c.rank as u8));
+        hand.hash(&mut hasher);
+    }
+    game.tavern_deck.cards.hash(&mut hasher);
+    game.discard_pile.hash(&mut hasher);
+    game.played_cards.hash(&mut hasher);
+    game.castle_deck.len().hash(&mut hasher);
+    if let Some(enemy) = &game.current_enemy {
+        (enemy.card, enemy.current_hp, enemy.immunity_cancelled).hash(&mut hasher);
+    }
+    (game.shield_value, game.total_damage, game.phase).hash(&mut hasher);
+    (game.current_player, game.consecutive_yields).hash(&mut hasher);
+    game.jester_played_this_turn.hash(&mut hasher);
+    game.rng_position().hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Every action worth trying, most promising first: kills (exact ones
+/// first), then the hardest hits, then yielding, then the smallest discards,
+/// with a solo Jester last
+fn candidate_actions(game: &Game, jester: bool) -> Vec<GameAction> {
+    let hand = &game.player().hand;
+    let mut actions = game.candidate_actions(false);
+    actions.retain(|action| jester || *action != GameAction::UseSoloJester);
+    actions.sort_by_cached_key(|action| match action {
+        GameAction::Play(play) => {
+            let Some(enemy) = &game.current_enemy else {
+                return (0, 0, 0);
+            };
+            let hp = enemy.current_hp as u32;
+            let cards: Vec<Card> = play.iter().map(|&i| hand[i]).collect();
+            let (damage, _) = strike(game.shield_value, enemy, &cards);
+            // Exact kills, then the smallest overkill, then the most damage
+            match damage {
+                d if d == hp => (0, 0, 0),
+                d if d > hp => (0, d - hp, 0),
+                d => (0, 1000 - d, 0),
+            }
+        }
+        GameAction::Yield => (1, 0, 0),
+        GameAction::Discard(cards) => {
+            let total: u32 = cards.iter().map(|&i| hand[i].value() as u32).sum();
+            (2, total, cards.len())
+        }
+        GameAction::ChooseNextPlayer(player) => (3, 0, *player),
+        GameAction::UseSoloJester => (4, 0, 0),
+    });
+    actions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Rank, Suit};
+    use crate::enemy::Enemy;
+    use crate::game::VictoryRank;
+
+    /// A solo game down to the last King, with nothing left to draw
+    fn last_king(hp: u8, hand: Vec<Card>) -> Game {
+        let mut game = Game::new_solo_with_seed(3);
+        game.castle_deck.cards.clear();
+        game.tavern_deck.cards.clear();
+        game.current_enemy = Some(Enemy::new(Card::new(Suit::Spades, Rank::King)));
+        game.current_enemy.as_mut().unwrap().current_hp = hp;
+        game.players[0].hand = hand;
+        game.jesters_used = game.jester_count;
+        game
+    }
+
+    #[test]
+    fn test_solver_finds_a_line_through_the_attack() {
+        // The Spades are no use against a Spades King, but cover its attack
+        // after the doubled 9♣, leaving the Eight to finish it
+        let game = last_king(
+            26,
+            vec![
+                Card::new(Suit::Clubs, Rank::Nine),
+                Card::new(Suit::Spades, Rank::Ten),
+                Card::new(Suit::Diamonds, Rank::Eight),
+                Card::new(Suit::Spades, Rank::Jack),
+            ],
+        );
+
+        let Verdict::Winnable {
+            jesters: 0,
+            line,
+            fewest: true,
+        } = Solver::new(10_000).solve(&game)
+        else {
+            panic!("the King can be beaten");
+        };
+        let mut replayed = game.clone();
+        for action in line {
+            replayed.apply(action).unwrap();
+        }
+        assert!(matches!(replayed.game_state, GameState::Victory));
+    }
+
+    #[test]
+    fn test_solver_proves_a_hopeless_deal_unwinnable() {
+        let game = last_king(
+            40,
+            vec![
+                Card::new(Suit::Hearts, Rank::Nine),
+                Card::new(Suit::Clubs, Rank::Four),
+            ],
+        );
+        assert_eq!(Solver::new(10_000).solve(&game), Verdict::Unwinnable);
+    }
+
+    #[test]
+    fn test_known_gold_seed_stays_winnable() {
+        // Guards against rules changes that make this deal unwinnable
+        let Verdict::Winnable {
+            jesters: 0, line, ..
+        } = solve_seed(19, DEFAULT_NODE_LIMIT)
+        else {
+            panic!("seed 19 has a Gold line");
+        };
+        let mut game = Game::new_solo_with_seed(19);
+        for action in line {
+            game.apply(action).unwrap();
+        }
+        assert_eq!(game.victory_rank(), Some(VictoryRank::Gold));
+    }
+}
@@ -1,21 +1,45 @@
-use crate::card::Card;
-use crate::game::Game;
+use crate::chat_box::ChatBox;
 use chrono::Local;
+use kingslayer::chat::SIGNALS;
+use kingslayer::net::Lobby;
+use kingslayer::{Card, ChatMessage, ChatMode, GameView, Hint, Phase, Suit};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, Paragraph},
     Frame,
 };
 
+/// Table talk, shown in place of the game guide in multiplayer games
+pub struct ChatPanel<'a> {
+    pub messages: &'a [ChatMessage],
+    pub mode: ChatMode,
+    pub chat_box: Option<&'a ChatBox>, // Message being written, if any
+    pub error: Option<&'a str>,        // Why the last message was blocked
+}
+
+/// Cards to pick out in the hand pane
+#[derive(Default)]
+pub struct HandMarks<'a> {
+    pub selected: &'a [usize],
+    pub errors: &'a [usize],    // Rejected by the last action
+    pub hint: Option<&'a Hint>, // Shown after pressing '?'
+}
+
+/// What fills the bottom-right corner of the table
+pub enum InfoPane<'a> {
+    Guide(usize), // Scroll offset
+    Chat(ChatPanel<'a>),
+}
+
 /// Render the main game UI with 3 rows
 pub fn render_game(
     f: &mut Frame,
-    game: &Game,
-    selected_cards: &[usize],
+    game: &GameView,
+    marks: HandMarks,
     log_scroll_offset: usize,
-    guide_scroll_offset: usize,
+    info: InfoPane,
     action_prompt: &str,
 ) {
     // Split into 3 rows
@@ -51,13 +75,21 @@ pub fn render_game(
     render_castle(f, top_chunks[0], game);
     render_battlefield(f, top_chunks[1], game, action_prompt);
     render_log(f, top_chunks[2], game, log_scroll_offset);
-    render_hand(f, main_chunks[1], game, selected_cards);
+    render_hand(f, main_chunks[1], game, &marks);
     render_keyboard_actions(f, bottom_chunks[0]);
-    render_game_guide(f, bottom_chunks[1], guide_scroll_offset);
+    match info {
+        InfoPane::Guide(scroll_offset) => render_game_guide(f, bottom_chunks[1], scroll_offset),
+        InfoPane::Chat(chat) => {
+            render_chat(f, bottom_chunks[1], &chat);
+            if let Some(ChatBox::Picking(cursor)) = chat.chat_box {
+                render_signal_picker(f, *cursor);
+            }
+        }
+    }
 }
 
 /// Render the Castle pane (current enemy) with logo and clock on top
-fn render_castle(f: &mut Frame, area: Rect, game: &Game) {
+fn render_castle(f: &mut Frame, area: Rect, game: &GameView) {
     // Split into top row (logo + date/time) and castle
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -88,7 +120,12 @@ fn render_castle(f: &mut Frame, area: Rect, game: &Game) {
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )),
-        Line::from(""),
+        Line::from(Span::styled(
+            game.seed
+                .map(|seed| format!("Seed: {}", seed))
+                .unwrap_or_default(),
+            Style::default().fg(Color::Gray),
+        )),
     ];
     let logo_text = Paragraph::new(Text::from(logo_lines))
         .block(logo_block)
@@ -169,10 +206,10 @@ fn render_castle(f: &mut Frame, area: Rect, game: &Game) {
             )));
         } else {
             let immune_suit = match enemy.card.suit {
-                crate::card::Suit::Hearts => "Hearts ♥",
-                crate::card::Suit::Diamonds => "Diamonds ♦",
-                crate::card::Suit::Clubs => "Clubs ♣",
-                crate::card::Suit::Spades => "Spades ♠",
+                Suit::Hearts => "Hearts ♥",
+                Suit::Diamonds => "Diamonds ♦",
+                Suit::Clubs => "Clubs ♣",
+                Suit::Spades => "Spades ♠",
             };
             text_lines.push(Line::from(Span::styled(
                 format!("Immune: {}", immune_suit),
@@ -194,7 +231,7 @@ fn render_castle(f: &mut Frame, area: Rect, game: &Game) {
 }
 
 /// Render the Battlefield pane (played cards, shields, damage)
-fn render_battlefield(f: &mut Frame, area: Rect, game: &Game, action_prompt: &str) {
+fn render_battlefield(f: &mut Frame, area: Rect, game: &GameView, action_prompt: &str) {
     // Split battlefield into stats rows, battlefield, and action prompt
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -222,7 +259,7 @@ fn render_battlefield(f: &mut Frame, area: Rect, game: &Game, action_prompt: &st
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Yellow));
     let castle_deck_text = Paragraph::new(Span::styled(
-        format!("{} enemies", game.castle_deck.len()),
+        format!("{} enemies", game.castle_remaining),
         Style::default()
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD),
@@ -237,7 +274,7 @@ fn render_battlefield(f: &mut Frame, area: Rect, game: &Game, action_prompt: &st
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Gray));
     let discard_text = Paragraph::new(Span::styled(
-        format!("{} cards", game.discard_pile.len()),
+        format!("{} cards", game.discard_count),
         Style::default()
             .fg(Color::Gray)
             .add_modifier(Modifier::BOLD),
@@ -252,7 +289,7 @@ fn render_battlefield(f: &mut Frame, area: Rect, game: &Game, action_prompt: &st
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Green));
     let tavern_text = Paragraph::new(Span::styled(
-        format!("{} cards", game.tavern_deck.len()),
+        format!("{} cards", game.tavern_remaining),
         Style::default()
             .fg(Color::Green)
             .add_modifier(Modifier::BOLD),
@@ -371,20 +408,47 @@ fn render_battlefield(f: &mut Frame, area: Rect, game: &Game, action_prompt: &st
     f.render_widget(text_paragraph, chunks[3]);
 }
 
-/// Render the Hand pane (player's cards)
-fn render_hand(f: &mut Frame, area: Rect, game: &Game, selected_cards: &[usize]) {
-    let block = Block::default()
-        .title(format!(
+/// Render the Hand pane (player's cards), flagging cards rejected by the last
+/// action and the cards a hint suggests
+fn render_hand(f: &mut Frame, area: Rect, game: &GameView, marks: &HandMarks) {
+    let selected_cards = marks.selected;
+    let hinted = marks.hint.map(Hint::cards).unwrap_or(&[]);
+    if game.spectating {
+        render_table_hands(f, area, game);
+        return;
+    }
+
+    let me = &game.players[game.seat];
+    let hand = game.hand();
+    let title = if game.is_solo() {
+        format!(
             "🃏 Your Hand ({}/{}) | Jesters: {}/{}",
-            game.player.hand_size(),
-            game.player.max_hand_size,
+            me.hand_size,
+            me.max_hand_size,
             game.jester_count - game.jesters_used,
             game.jester_count
-        ))
+        )
+    } else {
+        // Show whose hand this is and how many cards everyone holds
+        let table: Vec<String> = game
+            .players
+            .iter()
+            .map(|p| format!("{}: {}", p.name, p.hand_size))
+            .collect();
+        format!(
+            "🃏 {}'s Hand ({}/{}) | {}",
+            me.name,
+            me.hand_size,
+            me.max_hand_size,
+            table.join(", ")
+        )
+    };
+    let block = Block::default()
+        .title(title)
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Green));
 
-    if game.player.hand.is_empty() {
+    if hand.is_empty() {
         let paragraph = Paragraph::new("No cards in hand")
             .block(block)
             .alignment(Alignment::Center);
@@ -393,7 +457,8 @@ fn render_hand(f: &mut Frame, area: Rect, game: &Game, selected_cards: &[usize])
     }
 
     // Generate ASCII art for each card
-    let card_arts: Vec<Vec<String>> = game.player.hand.iter().map(render_card_small).collect();
+    let card_arts: Vec<Vec<String>> = hand.iter().map(render_card_small).collect();
+    let unplayable = game.unplayable_cards(selected_cards);
 
     // Number of lines in a card (should be 5)
     let card_height = 5;
@@ -407,13 +472,28 @@ fn render_hand(f: &mut Frame, area: Rect, game: &Game, selected_cards: &[usize])
         for (card_idx, card_art) in card_arts.iter().enumerate() {
             let is_selected = selected_cards.contains(&card_idx);
 
-            let style = if is_selected {
+            let style = if marks.errors.contains(&card_idx) {
+                Style::default()
+                    .fg(Color::White)
+                    .bg(Color::Red)
+                    .add_modifier(Modifier::BOLD)
+            } else if is_selected {
                 Style::default()
                     .fg(Color::Black)
                     .bg(Color::White)
                     .add_modifier(Modifier::BOLD)
+            } else if hinted.contains(&card_idx) {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else if unplayable.contains(&card_idx) {
+                // Can't be played with the cards already selected
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::DIM)
             } else {
-                let card = &game.player.hand[card_idx];
+                let card = &hand[card_idx];
                 let color = if card.suit.is_red() {
                     Color::Red
                 } else {
@@ -432,7 +512,7 @@ fn render_hand(f: &mut Frame, area: Rect, game: &Game, selected_cards: &[usize])
     // Add index line below cards (1-based numbering)
     // Each card is 8 chars wide, so index should also be 8 chars
     let mut index_spans = vec![];
-    for card_idx in 0..game.player.hand.len() {
+    for card_idx in 0..hand.len() {
         let is_selected = selected_cards.contains(&card_idx);
         let style = if is_selected {
             Style::default()
@@ -452,7 +532,7 @@ fn render_hand(f: &mut Frame, area: Rect, game: &Game, selected_cards: &[usize])
     // Add value line below indices
     // Each value label should also be 8 chars wide to match
     let mut value_spans = vec![];
-    for card in &game.player.hand {
+    for card in hand {
         let value_str = format!("Val:{}", card.value());
         value_spans.push(Span::styled(
             format!("{:^8}", value_str), // Center the value in 8 chars
@@ -462,6 +542,15 @@ fn render_hand(f: &mut Frame, area: Rect, game: &Game, selected_cards: &[usize])
     }
     text_lines.push(Line::from(value_spans));
 
+    if let Some(hint) = marks.hint {
+        text_lines.push(Line::from(Span::styled(
+            format!("💡 Hint: {}", hint.reason),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )));
+    }
+
     let paragraph = Paragraph::new(Text::from(text_lines))
         .block(block)
         .alignment(Alignment::Left);
@@ -469,8 +558,64 @@ fn render_hand(f: &mut Frame, area: Rect, game: &Game, selected_cards: &[usize])
     f.render_widget(paragraph, area);
 }
 
+/// Render every player's hand for a spectator, one line each: the cards if the
+/// host lets spectators see them, otherwise just how many there are
+fn render_table_hands(f: &mut Frame, area: Rect, game: &GameView) {
+    let block = Block::default()
+        .title("👀 Spectating: Players' Hands")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Green));
+
+    let lines: Vec<Line> = game
+        .players
+        .iter()
+        .enumerate()
+        .map(|(i, player)| {
+            let (marker, name_style) = if i == game.current_player {
+                (
+                    "▶ ",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                ("  ", Style::default().fg(Color::White))
+            };
+            let mut spans = vec![Span::styled(
+                format!(
+                    "{}{} ({}/{}): ",
+                    marker, player.name, player.hand_size, player.max_hand_size
+                ),
+                name_style,
+            )];
+            match &player.hand {
+                Some(hand) => {
+                    for card in hand {
+                        let color = if card.suit.is_red() {
+                            Color::Red
+                        } else {
+                            Color::White
+                        };
+                        spans.push(Span::styled(
+                            format!("{}{} ", card.rank.display(), card.suit.symbol()),
+                            Style::default().fg(color),
+                        ));
+                    }
+                }
+                None => spans.push(Span::styled(
+                    "🂠 ".repeat(player.hand_size),
+                    Style::default().fg(Color::Gray),
+                )),
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(Text::from(lines)).block(block), area);
+}
+
 /// Render the Log pane (game events)
-fn render_log(f: &mut Frame, area: Rect, game: &Game, scroll_offset: usize) {
+fn render_log(f: &mut Frame, area: Rect, game: &GameView, scroll_offset: usize) {
     let block = Block::default()
         .title("📜 Game Log (↑↓ to scroll)")
         .borders(Borders::ALL)
@@ -497,6 +642,92 @@ fn render_log(f: &mut Frame, area: Rect, game: &Game, scroll_offset: usize) {
     f.render_widget(list, area);
 }
 
+/// Render the Chat pane: the latest messages, and the one being typed
+fn render_chat(f: &mut Frame, area: Rect, chat: &ChatPanel) {
+    let title = match chat.mode {
+        ChatMode::Free => "💬 Chat ('c' to talk, no ranks or suits)",
+        ChatMode::Strict => "💬 Chat ('c' for signals, strict)",
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let mut lines: Vec<Line> = chat
+        .messages
+        .iter()
+        .map(|message| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{}: ", message.from),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(message.text.clone()),
+            ])
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "Nobody has said anything yet",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    if let Some(ChatBox::Typing(text)) = chat.chat_box {
+        lines.push(Line::from(Span::styled(
+            format!("> {}█", text),
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+    if let Some(error) = chat.error {
+        lines.push(Line::from(Span::styled(
+            format!("✗ {}", error),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    // Keep the newest lines in view
+    let available_height = area.height.saturating_sub(2) as usize;
+    let start = lines.len().saturating_sub(available_height);
+    let paragraph = Paragraph::new(Text::from(lines.split_off(start)))
+        .block(block)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
+
+/// Render strict chat's signals over the table, highlighting `cursor`
+fn render_signal_picker(f: &mut Frame, cursor: usize) {
+    let items: Vec<ListItem> = SIGNALS
+        .iter()
+        .enumerate()
+        .map(|(i, signal)| {
+            let style = if i == cursor {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            ListItem::new(Line::from(Span::styled(
+                format!(" {}. {}", i + 1, signal),
+                style,
+            )))
+        })
+        .collect();
+
+    let block = Block::default()
+        .title("💬 Send a signal (Esc to cancel) 💬")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let area = centered_rect(40, 35, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(List::new(items).block(block), area);
+}
+
 /// Render a card in compact ASCII art format (for hand display)
 /// Returns a vector of strings, one for each line of the card
 fn render_card_small(card: &Card) -> Vec<String> {
@@ -530,12 +761,17 @@ pub fn render_help(f: &mut Frame, scroll_offset: usize) {
         Line::from("  1-8: Toggle card selection"),
         Line::from("  Enter: Play selected cards"),
         Line::from("  Space: Yield turn"),
+        Line::from("  a: Select the least wasteful discard (when defending)"),
+        Line::from("  ?: Show a hint for the best move and why"),
         Line::from("  j: Use Jester power (solo mode only)"),
         Line::from("  ↑/↓: Scroll game log (or this help)"),
         Line::from("  ←/→: Scroll game guide"),
+        Line::from("  u / Ctrl-r: Undo / redo (solo mode only)"),
+        Line::from("  s: Save game"),
         Line::from("  r: Restart game"),
+        Line::from("  c: Chat (multiplayer; Enter sends, Esc cancels)"),
         Line::from("  h: Toggle help overlay"),
-        Line::from("  q: Quit game"),
+        Line::from("  q: Quit game (auto-saves)"),
         Line::from(""),
         Line::from(Span::styled(
             "GAME OBJECTIVE:",
@@ -720,6 +956,7 @@ pub fn render_help(f: &mut Frame, scroll_offset: usize) {
         Line::from("  Gold Victory: 0 Jesters used"),
         Line::from("  Silver Victory: 1 Jester used"),
         Line::from("  Bronze Victory: 2 Jesters used"),
+        Line::from("  Undoing a move makes a victory unranked"),
         Line::from(""),
         Line::from(Span::styled(
             "DEFEAT CONDITIONS:",
@@ -765,11 +1002,11 @@ pub fn render_help(f: &mut Frame, scroll_offset: usize) {
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from("  Standard 52-card deck (Ace-10 only, face cards removed)"),
-        Line::from("  Jester count by player count:"),
-        Line::from("    - Solo: 2 Jesters"),
-        Line::from("    - 2 players: 1 Jester"),
-        Line::from("    - 3 players: 0 Jesters"),
-        Line::from("    - 4 players: 0 Jesters"),
+        Line::from("  Jesters in the tavern deck by player count:"),
+        Line::from("    - Solo: 0 (2 Jester powers instead)"),
+        Line::from("    - 2 players: 0 Jesters"),
+        Line::from("    - 3 players: 1 Jester"),
+        Line::from("    - 4 players: 2 Jesters"),
         Line::from(""),
         Line::from(Span::styled(
             "Press 'h' to close this guide",
@@ -797,6 +1034,213 @@ pub fn render_help(f: &mut Frame, scroll_offset: usize) {
     f.render_widget(paragraph, area);
 }
 
+/// Prompt for the turn phase, or who the viewer is waiting on
+pub fn turn_prompt(game: &GameView) -> String {
+    if !game.is_my_turn() {
+        let waiting_on = &game.players[game.current_player].name;
+        return match game.phase {
+            Phase::Discard { .. } => format!("⏳ Waiting for {} to discard...", waiting_on),
+            Phase::ChooseNextPlayer => {
+                format!("⏳ Waiting for {} to choose who goes next...", waiting_on)
+            }
+            Phase::Play => format!("⏳ Waiting for {}...", waiting_on),
+        };
+    }
+
+    let cards = game.players[game.seat].max_hand_size;
+    match game.phase {
+        Phase::Play if game.must_play => format!(
+            "⚔️  ATTACK: Everyone else yielded! Select cards (1-{}) and press Enter to play",
+            cards
+        ),
+        Phase::Play => format!(
+            "⚔️  ATTACK: Select cards (1-{}) and press Enter to play, or Space to yield",
+            cards
+        ),
+        Phase::Discard { required } => {
+            format!(
+                "🛡️  DEFEND: Enemy attacks! Discard cards worth {} value or more ('a' to suggest)",
+                required
+            )
+        }
+        Phase::ChooseNextPlayer => {
+            "🃏 JESTER: Choose who goes next (↑/↓ and Enter, or 1-4)".to_string()
+        }
+    }
+}
+
+/// Render the hot-seat screen that hides the table until the next player is ready
+pub fn render_pass_screen(f: &mut Frame, player_name: &str, prompt: &str) {
+    render_notice(
+        f,
+        "🔒 Hot Seat 🔒",
+        &format!("Pass to {}", player_name),
+        prompt,
+    );
+}
+
+/// Render a centered notice in place of the table
+pub fn render_notice(f: &mut Frame, title: &str, heading: &str, prompt: &str) {
+    let block = Block::default()
+        .title(title.to_string())
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let text = Text::from(vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            heading.to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(prompt.to_string()),
+    ]);
+
+    let area = centered_rect(50, 30, f.area());
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    f.render_widget(paragraph, area);
+}
+
+/// Render the Jester's "who goes next" list over the table, highlighting `cursor`
+pub fn render_player_choice(f: &mut Frame, game: &GameView, cursor: usize) {
+    let items: Vec<ListItem> = game
+        .players
+        .iter()
+        .enumerate()
+        .map(|(i, player)| {
+            let you = if i == game.current_player {
+                " (you)"
+            } else {
+                ""
+            };
+            let text = format!(
+                " {}. {}{} - {} cards",
+                i + 1,
+                player.name,
+                you,
+                player.hand_size
+            );
+            let style = if i == cursor {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            ListItem::new(Line::from(Span::styled(text, style)))
+        })
+        .collect();
+
+    let block = Block::default()
+        .title("🃏 Who goes next? 🃏")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let area = centered_rect(40, 30, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(List::new(items).block(block), area);
+}
+
+/// Render the LAN lobby: seat order, who is ready, and how to join.
+/// `cursor` highlights a member for the host to move.
+pub fn render_lobby(
+    f: &mut Frame,
+    lobby: &Lobby,
+    heading: &str,
+    cursor: Option<usize>,
+    prompt: &str,
+) {
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            heading.to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from("Seat order:"),
+    ];
+
+    for seat in 0..4 {
+        let Some(member) = lobby.members.get(seat) else {
+            lines.push(Line::from(Span::styled(
+                format!(" {}. (open)", seat + 1),
+                Style::default().fg(Color::DarkGray),
+            )));
+            continue;
+        };
+
+        let mut name = member.name.clone();
+        if member.is_host {
+            name.push_str(" (host)");
+        }
+        if lobby.you == Some(seat) {
+            name.push_str(" (you)");
+        }
+        let (status, status_color) = if member.ready {
+            ("✔ Ready", Color::Green)
+        } else {
+            ("✗ Not ready", Color::Red)
+        };
+        let style = if cursor == Some(seat) {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {}. {:<24}", seat + 1, name), style),
+            Span::styled(status, Style::default().fg(status_color)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    let hands = if lobby.settings.spectators_see_hands {
+        "can see every hand"
+    } else {
+        "can't see hands"
+    };
+    lines.push(Line::from(Span::styled(
+        format!("👀 Spectators: {} ({})", lobby.spectators, hands),
+        Style::default().fg(Color::Cyan),
+    )));
+    let chat = match lobby.settings.chat_mode {
+        ChatMode::Free => "free text, no ranks or suits",
+        ChatMode::Strict => "strict, signals only",
+    };
+    lines.push(Line::from(Span::styled(
+        format!("💬 Chat: {}", chat),
+        Style::default().fg(Color::Cyan),
+    )));
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(prompt.to_string()));
+
+    let block = Block::default()
+        .title("🌐 LAN Lobby 🌐")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let area = centered_rect(60, 50, f.area());
+    let paragraph = Paragraph::new(Text::from(lines))
+        .block(block)
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
+
 /// Helper function to create a centered rectangle
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -835,6 +1279,8 @@ fn render_keyboard_actions(f: &mut Frame, area: Rect) {
         Line::from("  1-8: Toggle card selection"),
         Line::from("  Enter: Play selected cards"),
         Line::from("  Space: Yield turn"),
+        Line::from("  a: Suggest a discard"),
+        Line::from("  ?: Hint at a good move"),
         Line::from("  j: Use Jester power"),
         Line::from(""),
         Line::from(Span::styled(
@@ -852,9 +1298,12 @@ fn render_keyboard_actions(f: &mut Frame, area: Rect) {
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         )),
+        Line::from("  u / Ctrl-r: Undo / redo"),
+        Line::from("  s: Save game"),
         Line::from("  r: Restart game"),
+        Line::from("  c: Chat (multiplayer)"),
         Line::from("  h: Toggle help overlay"),
-        Line::from("  q: Quit game"),
+        Line::from("  q: Quit game (auto-saves)"),
     ]);
 
     let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Left);
@@ -998,5 +1447,5 @@ pub fn get_game_guide_line_count() -> usize {
 
 /// Get the total number of lines in the help overlay (for scrolling)
 pub fn get_help_line_count() -> usize {
-    177 // Total lines in the help overlay
+    180 // Total lines in the help overlay
 }
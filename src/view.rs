@@ -0,0 +1,221 @@
+use crate::card::Card;
+use crate::enemy::Enemy;
+use crate::game::{self, Game, GameState, Phase};
+use crate::player;
+use serde::{Deserialize, Serialize};
+
+/// What one seat may see of another player: their hand only if it is the viewer's own
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerView {
+    pub name: String,
+    pub hand_size: usize,
+    pub max_hand_size: usize,
+    pub hand: Option<Vec<Card>>, // Only filled in for the viewing seat
+}
+
+/// The part of a game one seat is allowed to see: its own hand, and only the
+/// hand counts of everyone else. Decks are reduced to their sizes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameView {
+    pub seat: usize, // Seat whose hand is shown; the current player's for spectators
+    pub players: Vec<PlayerView>,
+    pub current_player: usize,
+    pub current_enemy: Option<Enemy>,
+    pub castle_remaining: usize,
+    pub tavern_remaining: usize,
+    pub discard_count: usize,
+    pub played_cards: Vec<Card>,
+    pub shield_value: u8,
+    pub total_damage: u8,
+    pub game_state: GameState,
+    pub phase: Phase,
+    pub game_log: Vec<String>,
+    pub jester_count: u8,
+    pub jesters_used: u8,
+    pub seed: Option<u64>, // Hidden from network players, since it reveals the deal
+    #[serde(default)]
+    pub spectating: bool, // Watching without a seat
+    #[serde(default)]
+    pub must_play: bool, // Every other player yielded, so the current player can't
+}
+
+impl GameView {
+    /// Filter a game down to what `seat` may see
+    pub fn for_seat(game: &Game, seat: usize) -> Self {
+        let players = game
+            .players
+            .iter()
+            .enumerate()
+            .map(|(i, player)| PlayerView {
+                name: player.name.clone(),
+                hand_size: player.hand_size(),
+                max_hand_size: player.max_hand_size,
+                hand: (i == seat).then(|| player.hand.clone()),
+            })
+            .collect();
+
+        Self {
+            seat,
+            players,
+            current_player: game.current_player,
+            current_enemy: game.current_enemy.clone(),
+            castle_remaining: game.castle_deck.len(),
+            tavern_remaining: game.tavern_deck.len(),
+            discard_count: game.discard_pile.len(),
+            played_cards: game.played_cards.clone(),
+            shield_value: game.shield_value,
+            total_damage: game.total_damage,
+            game_state: game.game_state.clone(),
+            phase: game.phase,
+            game_log: game.game_log.clone(),
+            jester_count: game.jester_count,
+            jesters_used: game.jesters_used,
+            seed: None,
+            spectating: false,
+            must_play: !game.can_yield(),
+        }
+    }
+
+    /// Filter a game down to what a spectator may see: every hand if
+    /// `show_hands`, otherwise none
+    pub fn for_spectator(game: &Game, show_hands: bool) -> Self {
+        let mut view = Self::for_seat(game, game.current_player);
+        for (player, view) in game.players.iter().zip(&mut view.players) {
+            view.hand = show_hands.then(|| player.hand.clone());
+        }
+        view.spectating = true;
+        view
+    }
+
+    /// The viewing seat's own hand
+    pub fn hand(&self) -> &[Card] {
+        self.players[self.seat].hand.as_deref().unwrap_or(&[])
+    }
+
+    /// Every set of cards the viewing seat may play from its hand
+    pub fn legal_plays(&self) -> Vec<Vec<usize>> {
+        game::legal_plays_in(self.hand())
+    }
+
+    /// Hand cards that can't join the current selection in any legal play,
+    /// or none when it isn't this seat's turn to play
+    pub fn unplayable_cards(&self, selected: &[usize]) -> Vec<usize> {
+        if self.phase != Phase::Play || !self.is_my_turn() || self.is_over() {
+            return Vec::new();
+        }
+        let plays = self.legal_plays();
+        (0..self.hand().len())
+            .filter(|i| !selected.contains(i))
+            .filter(|i| {
+                !plays
+                    .iter()
+                    .any(|play| play.contains(i) && selected.iter().all(|s| play.contains(s)))
+            })
+            .collect()
+    }
+
+    /// The least wasteful discard from the viewing seat's hand, when it is
+    /// this seat's turn to discard and the hand can cover the attack
+    pub fn suggested_discard(&self) -> Option<Vec<usize>> {
+        match self.phase {
+            Phase::Discard { required } if self.is_my_turn() && !self.is_over() => {
+                player::best_discard_in(self.hand(), required, self.current_enemy.as_ref())
+            }
+            _ => None,
+        }
+    }
+
+    /// Check if this is a solo game
+    pub fn is_solo(&self) -> bool {
+        self.players.len() == 1
+    }
+
+    /// Check if the solo Jester power is still available
+    pub fn can_use_solo_jester(&self) -> bool {
+        self.is_solo() && self.jesters_used < self.jester_count
+    }
+
+    /// Check if it is the viewing seat's turn
+    pub fn is_my_turn(&self) -> bool {
+        !self.spectating && self.current_player == self.seat
+    }
+
+    /// Check if the game has ended in victory or defeat
+    pub fn is_over(&self) -> bool {
+        !matches!(self.game_state, GameState::Playing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Rank, Suit};
+
+    #[test]
+    fn test_view_hides_other_hands() {
+        let game = Game::new_with_seed(3, 21);
+        let view = GameView::for_seat(&game, 1);
+
+        assert_eq!(view.hand(), game.players[1].hand.as_slice());
+        assert!(view.players[0].hand.is_none());
+        assert!(view.players[2].hand.is_none());
+        assert_eq!(view.players[2].hand_size, game.players[2].hand.len());
+        assert_eq!(view.tavern_remaining, game.tavern_deck.len());
+        assert_eq!(view.seed, None);
+
+        // Nothing about the tavern order or other hands leaks into the wire format
+        let json = serde_json::to_string(&view).unwrap();
+        assert!(!json.contains("tavern_deck"));
+        assert!(!json.contains("rng"));
+
+        // Nor does the seed, which would reveal the whole deal, even through the log
+        let game = Game::new_with_seed(2, 424242);
+        for view in [
+            GameView::for_seat(&game, 1),
+            GameView::for_spectator(&game, false),
+        ] {
+            let json = serde_json::to_string(&view).unwrap();
+            assert!(!json.contains("424242"));
+        }
+    }
+
+    #[test]
+    fn test_spectator_view_shows_hands_only_if_allowed() {
+        let game = Game::new_with_seed(3, 21);
+
+        let hidden = GameView::for_spectator(&game, false);
+        assert!(hidden.players.iter().all(|p| p.hand.is_none()));
+        assert!(hidden.hand().is_empty());
+        assert!(!hidden.is_my_turn());
+
+        let shown = GameView::for_spectator(&game, true);
+        for (player, view) in game.players.iter().zip(&shown.players) {
+            assert_eq!(view.hand.as_ref(), Some(&player.hand));
+        }
+        assert!(!shown.is_my_turn());
+    }
+
+    #[test]
+    fn test_unplayable_cards_follow_the_selection() {
+        let mut game = Game::new_solo_with_seed(22);
+        game.players[0].hand = vec![
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Spades, Rank::Five),
+            Card::new(Suit::Clubs, Rank::Ace),
+            Card::new(Suit::Diamonds, Rank::Six),
+        ];
+        let view = GameView::for_seat(&game, 0);
+
+        // Any card can start a play
+        assert!(view.unplayable_cards(&[]).is_empty());
+        // A Five goes with the other Five or the Ace, not the Six
+        assert_eq!(view.unplayable_cards(&[0]), vec![3]);
+        // Two Fives are a full combo
+        assert_eq!(view.unplayable_cards(&[0, 1]), vec![2, 3]);
+
+        // Nothing is dimmed outside the play phase
+        game.phase = Phase::Discard { required: 5 };
+        let view = GameView::for_seat(&game, 0);
+        assert!(view.unplayable_cards(&[0]).is_empty());
+    }
+}